@@ -62,6 +62,8 @@ pub enum GsmDecision {
     Requeue,
     #[default]
     DoDefault,
+    /// Not retried, the payment method was declined by the issuer and should not be reused
+    HardDecline,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
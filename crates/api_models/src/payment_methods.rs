@@ -910,6 +910,7 @@ impl From<CardDetailFromLocker> for payments::AdditionalCardInfo {
             card_holder_name: item.card_holder_name,
             payment_checks: None,
             authentication_data: None,
+            co_badged_card_networks: None,
         }
     }
 }
@@ -933,6 +934,7 @@ impl From<CardDetailFromLocker> for payments::AdditionalCardInfo {
             card_holder_name: item.card_holder_name,
             payment_checks: None,
             authentication_data: None,
+            co_badged_card_networks: None,
         }
     }
 }
@@ -882,6 +882,85 @@ impl RequestSurchargeDetails {
     }
 }
 
+/// Breakdown of the components that make up the net amount of a payment
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct AmountBreakdown {
+    /// The order amount, excluding shipping, taxes and surcharge
+    #[schema(value_type = i64, example = 6540)]
+    pub base_amount: MinorUnit,
+    /// Shipping cost of the order
+    #[schema(value_type = Option<i64>, example = 500)]
+    pub shipping_cost: Option<MinorUnit>,
+    /// Order tax amount calculated by tax connectors
+    #[schema(value_type = Option<i64>, example = 300)]
+    pub order_tax_amount: Option<MinorUnit>,
+    /// Surcharge applied on the payment
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub surcharge_amount: Option<MinorUnit>,
+    /// Tax applied on the surcharge
+    #[schema(value_type = Option<i64>, example = 10)]
+    pub surcharge_tax_amount: Option<MinorUnit>,
+    /// Sum of all the components above
+    #[schema(value_type = i64, example = 7450)]
+    pub net_amount: MinorUnit,
+}
+
+impl AmountBreakdown {
+    /// Returns `None` when the sum of the components overflows
+    pub fn new(
+        base_amount: MinorUnit,
+        shipping_cost: Option<MinorUnit>,
+        order_tax_amount: Option<MinorUnit>,
+        surcharge_amount: Option<MinorUnit>,
+        surcharge_tax_amount: Option<MinorUnit>,
+    ) -> Option<Self> {
+        let net_amount = [
+            shipping_cost,
+            order_tax_amount,
+            surcharge_amount,
+            surcharge_tax_amount,
+        ]
+        .into_iter()
+        .flatten()
+        .try_fold(base_amount, MinorUnit::checked_add)?;
+        Some(Self {
+            base_amount,
+            shipping_cost,
+            order_tax_amount,
+            surcharge_amount,
+            surcharge_tax_amount,
+            net_amount,
+        })
+    }
+}
+
+/// Error details received from the connector for the latest payment attempt
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, ToSchema)]
+pub struct ConnectorErrorDetails {
+    /// Error code received from the connector
+    #[schema(example = "E0001")]
+    pub code: Option<String>,
+    /// Error message received from the connector. The detailed error reason is preferred over
+    /// the error message when the connector provides both
+    #[schema(example = "Failed while verifying the card")]
+    pub message: Option<String>,
+    /// Error code unified across the connectors
+    #[schema(example = "UE_9000")]
+    pub unified_code: Option<String>,
+    /// Error message unified across the connectors
+    #[schema(example = "Something went wrong")]
+    pub unified_message: Option<String>,
+}
+
+impl ConnectorErrorDetails {
+    pub fn is_empty(&self) -> bool {
+        self.code.is_none()
+            && self.message.is_none()
+            && self.unified_code.is_none()
+            && self.unified_message.is_none()
+    }
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct HeaderPayload {
     pub payment_confirm_source: Option<api_enums::PaymentSource>,
@@ -2135,6 +2214,10 @@ pub struct AdditionalCardInfo {
     /// Details about the threeds environment.
     /// This is a free form field and the structure varies from processor to processor
     pub authentication_data: Option<serde_json::Value>,
+
+    /// All the networks the card can be processed on, present only for co-badged cards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub co_badged_card_networks: Option<Vec<api_enums::CardNetwork>>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2195,6 +2278,10 @@ pub enum AdditionalPaymentData {
         #[serde(flatten)]
         details: Option<OpenBankingData>,
     },
+    NetworkToken {
+        #[serde(flatten)]
+        details: Option<additional_info::NetworkTokenAdditionalData>,
+    },
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -3203,6 +3290,7 @@ where
                 | PaymentMethodDataResponse::Wallet(_)
                 | PaymentMethodDataResponse::BankTransfer(_)
                 | PaymentMethodDataResponse::OpenBanking(_)
+                | PaymentMethodDataResponse::NetworkToken(_)
                 | PaymentMethodDataResponse::Voucher(_) => {
                     payment_method_data_response.serialize(serializer)
                 }
@@ -3235,6 +3323,7 @@ pub enum PaymentMethodDataResponse {
     CardRedirect(Box<CardRedirectResponse>),
     CardToken(Box<CardTokenResponse>),
     OpenBanking(Box<OpenBankingResponse>),
+    NetworkToken(Box<NetworkTokenResponse>),
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -3294,6 +3383,13 @@ pub struct OpenBankingResponse {
     details: Option<OpenBankingData>,
 }
 
+#[derive(Eq, PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct NetworkTokenResponse {
+    #[serde(flatten)]
+    #[schema(value_type = Option<NetworkTokenAdditionalData>)]
+    details: Option<additional_info::NetworkTokenAdditionalData>,
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct RealTimePaymentDataResponse {
     #[serde(flatten)]
@@ -3460,13 +3556,21 @@ pub struct AddressDetails {
 
 impl AddressDetails {
     pub fn get_optional_full_name(&self) -> Option<Secret<String>> {
-        match (self.first_name.as_ref(), self.last_name.as_ref()) {
-            (Some(first_name), Some(last_name)) => Some(Secret::new(format!(
-                "{} {}",
-                first_name.peek(),
-                last_name.peek()
-            ))),
-            (Some(name), None) | (None, Some(name)) => Some(name.to_owned()),
+        let first_name = self
+            .first_name
+            .as_ref()
+            .map(|first_name| first_name.peek().trim())
+            .filter(|first_name| !first_name.is_empty());
+        let last_name = self
+            .last_name
+            .as_ref()
+            .map(|last_name| last_name.peek().trim())
+            .filter(|last_name| !last_name.is_empty());
+        match (first_name, last_name) {
+            (Some(first_name), Some(last_name)) => {
+                Some(Secret::new(format!("{first_name} {last_name}")))
+            }
+            (Some(name), None) | (None, Some(name)) => Some(Secret::new(name.to_string())),
             _ => None,
         }
     }
@@ -3647,6 +3751,8 @@ pub enum NextActionData {
     FetchQrCodeInformation {
         #[schema(value_type = String)]
         qr_code_fetch_url: Url,
+        /// The time until which the fetched qr code is to be displayed
+        display_to_timestamp: Option<i64>,
     },
     /// Contains the download url and the reference number for transaction
     DisplayVoucherInformation {
@@ -3657,6 +3763,8 @@ pub enum NextActionData {
     WaitScreenInformation {
         display_from_timestamp: i128,
         display_to_timestamp: Option<i128>,
+        /// Poll configuration for the sdk to use while the wait screen is displayed
+        poll_config: PollConfigResponse,
     },
     /// Contains the information regarding three_ds_method_data submission, three_ds authentication, and authorization flows
     ThreeDsInvoke {
@@ -3665,6 +3773,14 @@ pub enum NextActionData {
     InvokeSdkClient {
         next_action_data: SdkNextActionData,
     },
+    /// Contains the url where the customer authorizes the access to their bank account for open banking flows
+    OpenBankingConsent {
+        #[schema(value_type = String)]
+        /// The url where the customer gives the consent
+        consent_url: Url,
+        /// The time until which the consent url is valid
+        expires_at: Option<i64>,
+    },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
@@ -3736,6 +3852,7 @@ pub struct SdkNextActionData {
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct FetchQrCodeInformation {
     pub qr_code_fetch_url: Url,
+    pub display_to_timestamp: Option<i64>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -3772,6 +3889,12 @@ pub struct WaitScreenInstructions {
     pub display_to_timestamp: Option<i128>,
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct OpenBankingConsentInstructions {
+    pub consent_url: Url,
+    pub expires_at: Option<i64>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum BankTransferInstructions {
@@ -3878,6 +4001,12 @@ pub struct PaymentsResponse {
     #[schema(value_type = i64, example = 6540)]
     pub net_amount: MinorUnit,
 
+    /// The net amount without the surcharge, to reconcile against settlement files. Present only when a surcharge is applied.
+    /// amount_excluding_surcharge = net_amount - surcharge_details.surcharge_amount - surcharge_details.tax_amount
+    #[schema(value_type = Option<i64>, example = 6540)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_excluding_surcharge: Option<MinorUnit>,
+
     /// The maximum amount that could be captured from the payment
     #[schema(value_type = i64, minimum = 100, example = 6540)]
     pub amount_capturable: MinorUnit,
@@ -3964,6 +4093,11 @@ pub struct PaymentsResponse {
     #[schema(value_type = Option<CaptureMethod>, example = "automatic")]
     pub capture_method: Option<api_enums::CaptureMethod>,
 
+    /// Describes how the funds were actually captured, `not_captured` when the payment was only authorized
+    #[schema(value_type = Option<CaptureExecuted>, example = "manual")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_executed: Option<api_enums::CaptureExecuted>,
+
     /// The payment method that is to be used
     #[schema(value_type = PaymentMethod, example = "bank_transfer")]
     pub payment_method: Option<api_enums::PaymentMethod>,
@@ -3973,6 +4107,16 @@ pub struct PaymentsResponse {
     #[serde(serialize_with = "serialize_payment_method_data_response")]
     pub payment_method_data: Option<PaymentMethodDataResponseWithBilling>,
 
+    /// The card network the payment was processed on, for co-badged cards this is the network that was picked
+    #[schema(value_type = Option<CardNetwork>, example = "CartesBancaires")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_card_network: Option<api_enums::CardNetwork>,
+
+    /// The card networks the card can be processed on, contains more than one network for co-badged cards
+    #[schema(value_type = Option<Vec<CardNetwork>>, example = json!(["CartesBancaires", "Visa"]))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_card_networks: Option<Vec<api_enums::CardNetwork>>,
+
     /// Provide a reference to a stored payment method
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
     pub payment_token: Option<String>,
@@ -4076,13 +4220,37 @@ pub struct PaymentsResponse {
     /// If true the payment can be retried with same or different payment method which means the confirm call can be made again.
     pub manual_retry_allowed: Option<bool>,
 
+    /// If true the customer should be offered a different payment method, since the failure was a hard decline on the current one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggest_alternate_payment_method: Option<bool>,
+
     /// A unique identifier for a payment provided by the connector
     #[schema(value_type = Option<String>, example = "993672945374576J")]
     pub connector_transaction_id: Option<String>,
 
+    /// The connector's own request or trace identifier, captured from its response when the connector call failed
+    #[schema(value_type = Option<String>, example = "req_1234567890")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_request_id: Option<String>,
+
+    /// Identifier of the customer created at the connector for this payment
+    #[schema(value_type = Option<String>, example = "cus_1234567890")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_customer_id: Option<String>,
+
     /// Frm message contains information about the frm response
     pub frm_message: Option<FrmMessage>,
 
+    /// Risk score returned by the fraud check, same as `frm_message.frm_score`
+    #[schema(example = 80)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<i32>,
+
+    /// Decision taken by the fraud check, same as `frm_message.frm_status`
+    #[schema(example = "legit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_decision: Option<String>,
+
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
     pub metadata: Option<serde_json::Value>,
@@ -4091,6 +4259,11 @@ pub struct PaymentsResponse {
     #[schema(value_type = Option<ConnectorMetadata>)]
     pub connector_metadata: Option<serde_json::Value>, // This is Value because it is fetched from DB and before putting in DB the type is validated
 
+    /// Status of the order at the buy now pay later connector (e.g. Klarna), which is tracked separately from the payment status
+    #[schema(value_type = Option<BnplOrderStatus>, example = "PART_CAPTURED")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bnpl_order_status: Option<BnplOrderStatus>,
+
     /// Additional data that might be required by hyperswitch, to enable some specific features.
     #[schema(value_type = Option<FeatureMetadata>)]
     pub feature_metadata: Option<serde_json::Value>, // This is Value because it is fetched from DB and before putting in DB the type is validated
@@ -4101,6 +4274,11 @@ pub struct PaymentsResponse {
 
     /// Details for Payment link
     pub payment_link: Option<PaymentLinkResponse>,
+
+    /// Indicates whether the payment link has expired, not present when the payment has no payment link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_link_expired: Option<bool>,
+
     /// The business profile that is associated with this payment
     #[schema(value_type = Option<String>)]
     pub profile_id: Option<id_type::ProfileId>,
@@ -4130,6 +4308,10 @@ pub struct PaymentsResponse {
     /// Details of external authentication
     pub external_authentication_details: Option<ExternalAuthenticationDetailsResponse>,
 
+    /// Indicates whether an authentication value (CAVV) was obtained during external authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cavv_present: Option<bool>,
+
     /// Flag indicating if external 3ds authentication is made or not
     pub external_3ds_authentication_attempted: Option<bool>,
 
@@ -4177,8 +4359,33 @@ pub struct PaymentsResponse {
     /// order tax amount calculated by tax connectors
     pub order_tax_amount: Option<MinorUnit>,
 
+    /// Breakdown of the amount components that make up the net amount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_breakdown: Option<AmountBreakdown>,
+
     /// Connector Identifier for the payment method
     pub connector_mandate_id: Option<String>,
+
+    /// Error details received from the connector, grouped into a single object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_error_details: Option<ConnectorErrorDetails>,
+
+    /// Decline category of the connector error from the global status mapping, present only when the payment attempt failed
+    #[schema(value_type = Option<GsmDecision>, example = "retry")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decline_category: Option<crate::gsm::GsmDecision>,
+}
+
+/// Status of the order at the buy now pay later connector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BnplOrderStatus {
+    Authorized,
+    PartCaptured,
+    Captured,
+    Cancelled,
+    Expired,
+    Closed,
 }
 
 /// Fee information to be charged on the payment being collected
@@ -4290,6 +4497,12 @@ pub struct PaymentListResponse {
     pub size: usize,
     // The list of payments response objects
     pub data: Vec<PaymentsResponse>,
+    /// Whether more payments are available after this page
+    pub has_more: bool,
+    /// The identifier of the last payment in this page, to be passed as `starting_after` to fetch the next page
+    #[schema(value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<id_type::PaymentId>,
 }
 
 #[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
@@ -4460,6 +4673,7 @@ pub struct MandateValidationFields {
     pub mandate_data: Option<MandateData>,
     pub setup_future_usage: Option<api_enums::FutureUsage>,
     pub off_session: Option<bool>,
+    pub customer_acceptance: Option<CustomerAcceptance>,
 }
 
 impl From<&PaymentsRequest> for MandateValidationFields {
@@ -4482,6 +4696,7 @@ impl From<&PaymentsRequest> for MandateValidationFields {
             mandate_data: req.mandate_data.clone(),
             setup_future_usage: req.setup_future_usage,
             off_session: req.off_session,
+            customer_acceptance: req.customer_acceptance.clone(),
         }
     }
 }
@@ -4495,6 +4710,7 @@ impl From<&VerifyRequest> for MandateValidationFields {
             mandate_data: req.mandate_data.clone(),
             off_session: req.off_session,
             setup_future_usage: req.setup_future_usage,
+            customer_acceptance: None,
         }
     }
 }
@@ -4617,6 +4833,9 @@ impl From<AdditionalPaymentData> for PaymentMethodDataResponse {
             AdditionalPaymentData::OpenBanking { details } => {
                 Self::OpenBanking(Box::new(OpenBankingResponse { details }))
             }
+            AdditionalPaymentData::NetworkToken { details } => {
+                Self::NetworkToken(Box::new(NetworkTokenResponse { details }))
+            }
         }
     }
 }
@@ -4803,6 +5022,9 @@ pub struct DisplayAmountOnSdk {
     /// shipping cost for the order
     #[schema(value_type = String)]
     pub shipping_cost: Option<StringMajorUnit>,
+    /// surcharge amount applied on the order
+    #[schema(value_type = String)]
+    pub surcharge_amount: Option<StringMajorUnit>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -5654,6 +5876,16 @@ pub struct FrmMessage {
     pub frm_error: Option<String>,
 }
 
+impl FrmMessage {
+    pub fn get_risk_score(&self) -> Option<i32> {
+        self.frm_score
+    }
+
+    pub fn get_risk_decision(&self) -> Option<String> {
+        self.frm_status.clone()
+    }
+}
+
 mod payment_id_type {
     use std::{borrow::Cow, fmt};
 
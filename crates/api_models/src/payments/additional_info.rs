@@ -196,6 +196,25 @@ pub struct CardTokenAdditionalData {
     pub card_holder_name: Option<Secret<String>>,
 }
 
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct NetworkTokenAdditionalData {
+    /// Last 4 digits of the network token
+    #[schema(example = "4242")]
+    pub last4: Option<String>,
+    /// The card network of the network token
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<api_enums::CardNetwork>,
+    /// The type of the card backing the network token
+    #[schema(example = "credit")]
+    pub card_type: Option<String>,
+    /// Expiry month of the network token
+    #[schema(value_type = Option<String>, example = "12")]
+    pub token_exp_month: Option<Secret<String>>,
+    /// Expiry year of the network token
+    #[schema(value_type = Option<String>, example = "2030")]
+    pub token_exp_year: Option<Secret<String>>,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum UpiAdditionalData {
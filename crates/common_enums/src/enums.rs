@@ -375,6 +375,8 @@ pub enum CaptureStatus {
     Pending,
     // Capture request failed
     Failed,
+    // Capture was cancelled or voided at connector side
+    Cancelled,
 }
 
 #[derive(
@@ -474,6 +476,42 @@ pub enum CaptureMethod {
     ManualMultiple,
     /// The capture can be scheduled to automatically get triggered at a specific date & time
     Scheduled,
+    /// Handles separate auth and capture sequentially; same as `Automatic` for most connectors
+    SequentialAutomatic,
+}
+
+impl CaptureMethod {
+    /// Whether the capture is triggered by the connector right after authorization
+    pub fn is_automatic(self) -> bool {
+        match self {
+            Self::Automatic | Self::SequentialAutomatic => true,
+            Self::Manual | Self::ManualMultiple | Self::Scheduled => false,
+        }
+    }
+}
+
+/// Describes how the funds of a payment were actually captured
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CaptureExecuted {
+    /// The funds were captured right after authorization
+    Automatic,
+    /// The funds were captured through one or more capture requests
+    Manual,
+    /// The authorization is held or was released without capturing any funds
+    NotCaptured,
 }
 
 /// Type of the Connector for the financial use case. Could range from Payments to Accounting to Banking.
@@ -204,6 +204,14 @@ impl MerchantId {
         format!("should_call_gsm_{}", self.get_string_repr())
     }
 
+    /// get_prefer_sandbox_connectors_for_test_card_key
+    pub fn get_prefer_sandbox_connectors_for_test_card_key(&self) -> String {
+        format!(
+            "prefer_sandbox_connectors_for_test_card_{}",
+            self.get_string_repr()
+        )
+    }
+
     /// get_max_auto_single_connector_payout_retries_enabled_
     pub fn get_max_auto_single_connector_payout_retries_enabled(
         &self,
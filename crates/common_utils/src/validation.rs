@@ -9,7 +9,12 @@ use regex::Regex;
 #[cfg(feature = "logs")]
 use router_env::logger;
 
-use crate::errors::{CustomResult, ValidationError};
+use masking::PeekInterface;
+
+use crate::{
+    errors::{CustomResult, ValidationError},
+    pii,
+};
 
 /// Validates a given phone number using the [phonenumber] crate
 ///
@@ -60,6 +65,32 @@ pub fn validate_email(email: &str) -> CustomResult<(), ValidationError> {
     Ok(())
 }
 
+/// Validates that the serialized metadata does not exceed the given size in bytes
+///
+/// It returns a [ValidationError::InvalidValue] in case the metadata is larger than allowed
+pub fn validate_metadata_size(
+    metadata: &pii::SecretSerdeValue,
+    max_size_in_bytes: usize,
+) -> CustomResult<(), ValidationError> {
+    let metadata_size = serde_json::to_vec(metadata.peek())
+        .map_err(|_| {
+            report!(ValidationError::InvalidValue {
+                message: "Failed to serialize metadata".into()
+            })
+        })?
+        .len();
+
+    if metadata_size > max_size_in_bytes {
+        return Err(report!(ValidationError::InvalidValue {
+            message: format!(
+                "metadata exceeds the maximum allowed size of {max_size_in_bytes} bytes"
+            )
+        }));
+    }
+
+    Ok(())
+}
+
 /// Checks whether a given domain matches against a list of valid domain glob patterns
 pub fn validate_domain_against_allowed_domains(
     domain: &str,
@@ -105,6 +136,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_metadata_size() {
+        // `{"key":"<value>"}` serializes to 10 bytes in addition to the value
+        let metadata_of_size = |size: usize| {
+            pii::SecretSerdeValue::new(serde_json::json!({ "key": "a".repeat(size - 10) }))
+        };
+
+        assert!(validate_metadata_size(&metadata_of_size(16 * 1024), 16 * 1024).is_ok());
+        assert!(validate_metadata_size(&metadata_of_size(16 * 1024 + 1), 16 * 1024).is_err());
+    }
+
     #[test]
     fn test_validate_email() {
         let result = validate_email("abc@example.com");
@@ -83,6 +83,8 @@ pub struct PaymentAttempt {
     pub card_network: Option<String>,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub connector_request_id: Option<String>,
+    pub decline_category: Option<String>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "payment_v2")))]
@@ -159,6 +161,8 @@ pub struct PaymentAttempt {
     pub card_network: Option<String>,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub connector_request_id: Option<String>,
+    pub decline_category: Option<String>,
 }
 
 impl PaymentAttempt {
@@ -250,6 +254,8 @@ pub struct PaymentAttemptNew {
     pub card_network: Option<String>,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub connector_request_id: Option<String>,
+    pub decline_category: Option<String>,
 }
 
 impl PaymentAttemptNew {
@@ -413,6 +419,8 @@ pub enum PaymentAttemptUpdate {
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
         authentication_type: Option<storage_enums::AuthenticationType>,
+        connector_request_id: Option<String>,
+        decline_category: Option<String>,
     },
     CaptureUpdate {
         amount_to_capture: Option<MinorUnit>,
@@ -516,6 +524,8 @@ pub struct PaymentAttemptUpdateInternal {
     pub card_network: Option<String>,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub connector_request_id: Option<String>,
+    pub decline_category: Option<String>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -605,6 +615,8 @@ impl PaymentAttemptUpdate {
             card_network,
             shipping_cost,
             order_tax_amount,
+            connector_request_id,
+            decline_category,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -660,6 +672,8 @@ impl PaymentAttemptUpdate {
             card_network: card_network.or(source.card_network),
             shipping_cost: shipping_cost.or(source.shipping_cost),
             order_tax_amount: order_tax_amount.or(source.order_tax_amount),
+            connector_request_id: connector_request_id.or(source.connector_request_id),
+            decline_category: decline_category.or(source.decline_category),
             ..source
         }
     }
@@ -738,6 +752,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -792,6 +808,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -876,6 +894,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost,
                 order_tax_amount,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -931,6 +951,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -987,6 +1009,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -1043,6 +1067,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -1097,6 +1123,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -1169,6 +1197,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::ErrorUpdate {
                 connector,
@@ -1183,6 +1213,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_request_id,
+                decline_category,
             } => Self {
                 connector: connector.map(Some),
                 status: Some(status),
@@ -1197,6 +1229,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_request_id,
+                decline_category,
                 amount: None,
                 net_amount: None,
                 currency: None,
@@ -1284,6 +1318,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -1344,6 +1380,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -1405,6 +1443,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::PreprocessingUpdate {
                 status,
@@ -1464,6 +1504,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::CaptureUpdate {
                 multiple_capture_count,
@@ -1519,6 +1561,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -1574,6 +1618,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -1632,6 +1678,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
                 amount,
@@ -1686,6 +1734,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -1743,6 +1793,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -1803,6 +1855,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
         }
     }
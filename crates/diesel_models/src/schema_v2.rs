@@ -824,6 +824,10 @@ diesel::table! {
         card_network -> Nullable<Varchar>,
         shipping_cost -> Nullable<Int8>,
         order_tax_amount -> Nullable<Int8>,
+        #[max_length = 255]
+        connector_request_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        decline_category -> Nullable<Varchar>,
     }
 }
 
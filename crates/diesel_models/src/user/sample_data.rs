@@ -85,6 +85,8 @@ pub struct PaymentAttemptBatchNew {
     pub organization_id: common_utils::id_type::OrganizationId,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub connector_request_id: Option<String>,
+    pub decline_category: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -162,6 +164,8 @@ impl PaymentAttemptBatchNew {
             organization_id: self.organization_id,
             shipping_cost: self.shipping_cost,
             order_tax_amount: self.order_tax_amount,
+            connector_request_id: self.connector_request_id,
+            decline_category: self.decline_category,
         }
     }
 }
@@ -147,10 +147,12 @@ impl TryFrom<&FiservRouterData<&types::PaymentsAuthorizeRouterData>> for FiservP
             currency: item.router_data.request.currency.to_string(),
         };
         let transaction_details = TransactionDetails {
-            capture_flag: Some(matches!(
-                item.router_data.request.capture_method,
-                Some(enums::CaptureMethod::Automatic) | None
-            )),
+            capture_flag: Some(
+                item.router_data
+                    .request
+                    .capture_method
+                    .map_or(true, enums::CaptureMethod::is_automatic),
+            ),
             reversal_reason_code: None,
             merchant_transaction_id: item.router_data.connector_request_reference_id.clone(),
         };
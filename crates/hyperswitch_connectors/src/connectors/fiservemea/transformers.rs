@@ -103,8 +103,11 @@ impl TryFrom<&FiservemeaRouterData<&PaymentsAuthorizeRouterData>> for Fiservemea
                     },
                     security_code: req_card.card_cvc,
                 };
-                let request_type = if item.router_data.request.capture_method
-                    == Some(enums::CaptureMethod::Automatic)
+                let request_type = if item
+                    .router_data
+                    .request
+                    .capture_method
+                    .map_or(false, enums::CaptureMethod::is_automatic)
                 {
                     FiservemeaRequestType::PaymentCardSaleTransaction
                 } else {
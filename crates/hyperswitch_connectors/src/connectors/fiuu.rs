@@ -203,7 +203,9 @@ impl ConnectorValidation for Fiuu {
     ) -> CustomResult<(), errors::ConnectorError> {
         let capture_method = capture_method.unwrap_or_default();
         match capture_method {
-            CaptureMethod::Automatic | CaptureMethod::Manual => Ok(()),
+            CaptureMethod::Automatic
+            | CaptureMethod::SequentialAutomatic
+            | CaptureMethod::Manual => Ok(()),
             CaptureMethod::ManualMultiple | CaptureMethod::Scheduled => Err(
                 utils::construct_not_implemented_error_report(capture_method, self.id()),
             ),
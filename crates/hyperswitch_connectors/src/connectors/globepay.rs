@@ -195,7 +195,11 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         connectors: &Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
         let query_params = get_globlepay_query_params(&req.connector_auth_type)?;
-        if req.request.capture_method == Some(common_enums::enums::CaptureMethod::Automatic) {
+        if req
+            .request
+            .capture_method
+            .map_or(false, common_enums::enums::CaptureMethod::is_automatic)
+        {
             Ok(format!(
                 "{}api/v1.0/gateway/partners/{}/orders/{}{query_params}",
                 self.base_url(connectors),
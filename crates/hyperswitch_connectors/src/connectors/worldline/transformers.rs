@@ -533,7 +533,7 @@ fn get_status(item: (PaymentStatus, CaptureMethod)) -> AttemptStatus {
         PaymentStatus::Rejected => AttemptStatus::Failure,
         PaymentStatus::RejectedCapture => AttemptStatus::CaptureFailed,
         PaymentStatus::CaptureRequested => {
-            if capture_method == CaptureMethod::Automatic {
+            if capture_method.is_automatic() {
                 AttemptStatus::Pending
             } else {
                 AttemptStatus::CaptureInitiated
@@ -20,6 +20,34 @@ pub trait Conversion {
     where
         Self: Sized;
 
+    /// Converts back with `key`, retrying with `fallback_key` (if provided) when `key` cannot
+    /// decrypt the data. This allows data encrypted with the previous key to be read while a key
+    /// rotation is in progress.
+    async fn convert_back_with_fallback_key(
+        state: &KeyManagerState,
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+        fallback_key: Option<&Secret<Vec<u8>>>,
+        key_manager_identifier: Identifier,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized + Send,
+        Self::DstType: Clone + Send,
+    {
+        let result =
+            Self::convert_back(state, item.clone(), key, key_manager_identifier.clone()).await;
+        match (result, fallback_key) {
+            (Err(error), Some(fallback_key)) => {
+                router_env::logger::warn!(
+                    ?error,
+                    "Failed to convert back with the primary key, retrying with the fallback key"
+                );
+                Self::convert_back(state, item, fallback_key, key_manager_identifier).await
+            }
+            (result, _) => result,
+        }
+    }
+
     async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError>;
 }
 
@@ -231,6 +231,8 @@ pub struct PaymentAttempt {
     pub organization_id: id_type::OrganizationId,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub connector_request_id: Option<String>,
+    pub decline_category: Option<String>,
 }
 
 impl PaymentAttempt {
@@ -325,6 +327,8 @@ pub struct PaymentAttemptNew {
     pub organization_id: id_type::OrganizationId,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub connector_request_id: Option<String>,
+    pub decline_category: Option<String>,
 }
 
 impl PaymentAttemptNew {
@@ -484,6 +488,8 @@ pub enum PaymentAttemptUpdate {
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
         authentication_type: Option<storage_enums::AuthenticationType>,
+        connector_request_id: Option<String>,
+        decline_category: Option<String>,
     },
     CaptureUpdate {
         amount_to_capture: Option<MinorUnit>,
@@ -607,6 +613,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -661,6 +669,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -745,6 +755,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost,
                 order_tax_amount,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -800,6 +812,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -856,6 +870,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -912,6 +928,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -966,6 +984,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -1038,6 +1058,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::ErrorUpdate {
                 connector,
@@ -1052,6 +1074,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_request_id,
+                decline_category,
             } => Self {
                 connector: connector.map(Some),
                 status: Some(status),
@@ -1066,6 +1090,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_request_id,
+                decline_category,
                 amount: None,
                 net_amount: None,
                 currency: None,
@@ -1153,6 +1179,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -1213,6 +1241,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -1274,6 +1304,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::PreprocessingUpdate {
                 status,
@@ -1333,6 +1365,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::CaptureUpdate {
                 multiple_capture_count,
@@ -1388,6 +1422,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -1443,6 +1479,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -1501,6 +1539,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
                 amount,
@@ -1555,6 +1595,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -1612,6 +1654,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -1672,6 +1716,8 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
         }
     }
@@ -1762,6 +1808,8 @@ impl behaviour::Conversion for PaymentAttempt {
             organization_id: self.organization_id,
             card_network,
             order_tax_amount: self.order_tax_amount,
+            connector_request_id: self.connector_request_id,
+            decline_category: self.decline_category,
             shipping_cost: self.shipping_cost,
         })
     }
@@ -1839,6 +1887,8 @@ impl behaviour::Conversion for PaymentAttempt {
                 profile_id: storage_model.profile_id,
                 organization_id: storage_model.organization_id,
                 order_tax_amount: storage_model.order_tax_amount,
+                connector_request_id: storage_model.connector_request_id,
+                decline_category: storage_model.decline_category,
                 shipping_cost: storage_model.shipping_cost,
             })
         }
@@ -1920,6 +1970,8 @@ impl behaviour::Conversion for PaymentAttempt {
             organization_id: self.organization_id,
             card_network,
             order_tax_amount: self.order_tax_amount,
+            connector_request_id: self.connector_request_id,
+            decline_category: self.decline_category,
             shipping_cost: self.shipping_cost,
         })
     }
@@ -2004,6 +2056,8 @@ impl behaviour::Conversion for PaymentAttempt {
             organization_id: self.organization_id,
             card_network,
             order_tax_amount: self.order_tax_amount,
+            connector_request_id: self.connector_request_id,
+            decline_category: self.decline_category,
             shipping_cost: self.shipping_cost,
         })
     }
@@ -2081,6 +2135,8 @@ impl behaviour::Conversion for PaymentAttempt {
                 profile_id: storage_model.profile_id,
                 organization_id: storage_model.organization_id,
                 order_tax_amount: storage_model.order_tax_amount,
+                connector_request_id: storage_model.connector_request_id,
+                decline_category: storage_model.decline_category,
                 shipping_cost: storage_model.shipping_cost,
             })
         }
@@ -2162,6 +2218,8 @@ impl behaviour::Conversion for PaymentAttempt {
             organization_id: self.organization_id,
             card_network,
             order_tax_amount: self.order_tax_amount,
+            connector_request_id: self.connector_request_id,
+            decline_category: self.decline_category,
             shipping_cost: self.shipping_cost,
         })
     }
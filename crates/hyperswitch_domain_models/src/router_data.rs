@@ -64,6 +64,8 @@ pub struct RouterData<Flow, Request, Response> {
     pub test_mode: Option<bool>,
     pub connector_http_status_code: Option<u16>,
     pub external_latency: Option<u128>,
+    /// Request or trace identifier returned by the connector in its response headers
+    pub connector_request_id: Option<String>,
     /// Contains apple pay flow type simplified or manual
     pub apple_pay_flow: Option<payment_method_data::ApplePayFlow>,
 
@@ -112,6 +112,9 @@ pub struct PaymentsCaptureData {
     pub minor_payment_amount: MinorUnit,
     pub minor_amount_to_capture: MinorUnit,
     pub integrity_object: Option<CaptureIntegrityObject>,
+    // Shipping cost of the order, sent separately as it is not part of the capture amount
+    pub shipping_cost: Option<MinorUnit>,
+    pub merchant_order_reference_id: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -279,6 +282,8 @@ pub struct PaymentsPreProcessingData {
     pub mandate_id: Option<api_models::payments::MandateIds>,
     pub related_transaction_id: Option<String>,
     pub redirect_response: Option<CompleteAuthorizeRedirectResponse>,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub customer_name: Option<Secret<String>>,
 
     // New amount for amount frame work
     pub minor_amount: Option<MinorUnit>,
@@ -308,6 +313,8 @@ impl TryFrom<PaymentsAuthorizeData> for PaymentsPreProcessingData {
             related_transaction_id: data.related_transaction_id,
             redirect_response: None,
             enrolled_for_3ds: data.enrolled_for_3ds,
+            customer_id: data.customer_id,
+            customer_name: data.customer_name,
         })
     }
 }
@@ -336,6 +343,8 @@ impl TryFrom<CompleteAuthorizeData> for PaymentsPreProcessingData {
             related_transaction_id: None,
             redirect_response: data.redirect_response,
             enrolled_for_3ds: true,
+            customer_id: None,
+            customer_name: None,
         })
     }
 }
@@ -422,6 +431,8 @@ pub struct PaymentsSyncData {
     pub currency: storage_enums::Currency,
     pub payment_experience: Option<common_enums::PaymentExperience>,
     pub charges: Option<PaymentCharges>,
+    pub connector_customer: Option<String>,
+    pub merchant_order_reference_id: Option<String>,
 
     pub amount: MinorUnit,
     pub integrity_object: Option<SyncIntegrityObject>,
@@ -447,18 +458,25 @@ pub struct PaymentsCancelData {
 
     // minor amount data for amount framework
     pub minor_amount: Option<MinorUnit>,
+    pub connector_customer: Option<String>,
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct PaymentsRejectData {
     pub amount: Option<i64>,
     pub currency: Option<storage_enums::Currency>,
+    pub connector_meta: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct PaymentsApproveData {
     pub amount: Option<i64>,
     pub currency: Option<storage_enums::Currency>,
+    /// Reason given by the fraud check for the decision being approved
+    pub reason: Option<serde_json::Value>,
+    /// Transaction id of the fraud check decision being approved
+    pub decision_id: Option<String>,
+    pub connector_meta: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -794,11 +812,20 @@ pub struct PaymentsSessionData {
     pub country: Option<common_enums::CountryAlpha2>,
     pub surcharge_details: Option<SurchargeDetails>,
     pub order_details: Option<Vec<api_models::payments::OrderDetailsWithAmount>>,
+    pub wallet_merchant_data: Option<WalletMerchantData>,
+    pub connector_meta: Option<serde_json::Value>,
 
     // Minor Unit amount for amount frame work
     pub minor_amount: MinorUnit,
 }
 
+/// Merchant identifiers registered with the wallets, taken from the merchant connector account
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WalletMerchantData {
+    pub apple_pay_merchant_identifier: Option<String>,
+    pub google_pay_gateway_merchant_id: Option<String>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct PaymentsTaxCalculationData {
     pub amount: MinorUnit,
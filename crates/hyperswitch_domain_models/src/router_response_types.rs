@@ -67,6 +67,7 @@ pub enum PaymentsResponseData {
     },
     PostProcessingResponse {
         session_token: Option<api_models::payments::OpenBankingSessionToken>,
+        connector_metadata: Option<serde_json::Value>,
     },
     // SessionUpdateResponse {
     //     status: common_enums::SessionUpdateStatus,
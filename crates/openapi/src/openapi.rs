@@ -257,6 +257,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
         api_models::enums::CaptureMethod,
+        api_models::enums::CaptureExecuted,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
         api_models::enums::Connector,
@@ -383,6 +384,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsUpdateRequest,
         api_models::payments::PaymentsConfirmRequest,
         api_models::payments::PaymentsResponse,
+        api_models::payments::BnplOrderStatus,
         api_models::payments::PaymentsCreateResponseOpenApi,
         api_models::payments::PaymentRetrieveBody,
         api_models::payments::PaymentsRetrieveRequest,
@@ -457,6 +459,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::BacsBankTransferInstructions,
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
+        api_models::payments::AmountBreakdown,
+        api_models::payments::ConnectorErrorDetails,
         api_models::payments::PaymentAttemptResponse,
         api_models::payments::CaptureResponse,
         api_models::payments::PaymentsIncrementalAuthorizationRequest,
@@ -611,6 +615,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::BankTransferResponse,
         api_models::payments::CardRedirectResponse,
         api_models::payments::CardTokenResponse,
+        api_models::payments::NetworkTokenResponse,
         api_models::payments::CryptoResponse,
         api_models::payments::GiftCardResponse,
         api_models::payments::OpenBankingResponse,
@@ -618,6 +623,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::UpiResponse,
         api_models::payments::VoucherResponse,
         api_models::payments::additional_info::CardTokenAdditionalData,
+        api_models::payments::additional_info::NetworkTokenAdditionalData,
         api_models::payments::additional_info::BankDebitAdditionalData,
         api_models::payments::additional_info::AchBankDebitAdditionalData,
         api_models::payments::additional_info::BacsBankDebitAdditionalData,
@@ -826,6 +826,7 @@ pub enum StripeNextAction {
     },
     FetchQrCodeInformation {
         qr_code_fetch_url: url::Url,
+        display_to_timestamp: Option<i64>,
     },
     DisplayVoucherInformation {
         voucher_details: payments::VoucherNextStepData,
@@ -870,15 +871,20 @@ pub(crate) fn into_stripe_next_action(
             display_to_timestamp,
             qr_code_url,
         },
-        payments::NextActionData::FetchQrCodeInformation { qr_code_fetch_url } => {
-            StripeNextAction::FetchQrCodeInformation { qr_code_fetch_url }
-        }
+        payments::NextActionData::FetchQrCodeInformation {
+            qr_code_fetch_url,
+            display_to_timestamp,
+        } => StripeNextAction::FetchQrCodeInformation {
+            qr_code_fetch_url,
+            display_to_timestamp,
+        },
         payments::NextActionData::DisplayVoucherInformation { voucher_details } => {
             StripeNextAction::DisplayVoucherInformation { voucher_details }
         }
         payments::NextActionData::WaitScreenInformation {
             display_from_timestamp,
             display_to_timestamp,
+            ..
         } => StripeNextAction::WaitScreenInformation {
             display_from_timestamp,
             display_to_timestamp,
@@ -892,6 +898,14 @@ pub(crate) fn into_stripe_next_action(
         payments::NextActionData::InvokeSdkClient { next_action_data } => {
             StripeNextAction::InvokeSdkClient { next_action_data }
         }
+        payments::NextActionData::OpenBankingConsent { consent_url, .. } => {
+            StripeNextAction::RedirectToUrl {
+                redirect_to_url: RedirectUrl {
+                    return_url,
+                    url: Some(consent_url.to_string()),
+                },
+            }
+        }
     })
 }
 
@@ -468,6 +468,8 @@ pub(crate) async fn fetch_raw_secrets(
         webhook_source_verification_call: conf.webhook_source_verification_call,
         payment_method_auth,
         connector_request_reference_id_config: conf.connector_request_reference_id_config,
+        manual_retry_config: conf.manual_retry_config,
+        latency_header_config: conf.latency_header_config,
         #[cfg(feature = "payouts")]
         payouts: conf.payouts,
         applepay_decrypt_keys,
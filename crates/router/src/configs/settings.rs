@@ -92,6 +92,8 @@ pub struct Settings<S: SecretState> {
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
     pub payment_method_auth: SecretStateContainer<PaymentMethodAuth, S>,
     pub connector_request_reference_id_config: ConnectorRequestReferenceIdConfig,
+    pub manual_retry_config: ManualRetryConfig,
+    pub latency_header_config: LatencyHeaderConfig,
     #[cfg(feature = "payouts")]
     pub payouts: Payouts,
     pub payout_method_filters: ConnectorFilters,
@@ -733,6 +735,48 @@ pub struct LockerBasedRecipientConnectorList {
 pub struct ConnectorRequestReferenceIdConfig {
     pub merchant_ids_send_payment_id_as_connector_request_id:
         HashSet<common_utils::id_type::MerchantId>,
+    /// Scope of the connector request reference id for merchants not listed above
+    #[serde(default)]
+    pub default_scope: ConnectorRequestReferenceIdScope,
+}
+
+impl ConnectorRequestReferenceIdConfig {
+    pub fn get_scope(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> ConnectorRequestReferenceIdScope {
+        if self
+            .merchant_ids_send_payment_id_as_connector_request_id
+            .contains(merchant_id)
+        {
+            ConnectorRequestReferenceIdScope::Payment
+        } else {
+            self.default_scope
+        }
+    }
+}
+
+/// Scope within which the connector request reference id is unique
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorRequestReferenceIdScope {
+    /// The payment id is sent, so the reference stays the same across retries of a payment
+    Payment,
+    /// The attempt id is sent, so every attempt of a payment has its own reference
+    #[default]
+    Attempt,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ManualRetryConfig {
+    /// Maximum number of attempts after which manual retries are no longer allowed
+    pub max_attempts: Option<i16>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct LatencyHeaderConfig {
+    /// Minimum external latency, in milliseconds, for the `x-hs-latency` header to be sent
+    pub threshold_in_ms: u64,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -119,6 +119,7 @@ impl ConnectorValidation for Adyen {
                 | PaymentMethodType::Venmo
                 | PaymentMethodType::Paypal => match capture_method {
                     enums::CaptureMethod::Automatic
+                    | enums::CaptureMethod::SequentialAutomatic
                     | enums::CaptureMethod::Manual
                     | enums::CaptureMethod::ManualMultiple => Ok(()),
                     enums::CaptureMethod::Scheduled => {
@@ -137,7 +138,9 @@ impl ConnectorValidation for Adyen {
                 | PaymentMethodType::Klarna
                 | PaymentMethodType::Twint
                 | PaymentMethodType::Walley => match capture_method {
-                    enums::CaptureMethod::Automatic | enums::CaptureMethod::Manual => Ok(()),
+                    enums::CaptureMethod::Automatic
+                    | enums::CaptureMethod::SequentialAutomatic
+                    | enums::CaptureMethod::Manual => Ok(()),
                     enums::CaptureMethod::ManualMultiple | enums::CaptureMethod::Scheduled => {
                         capture_method_not_supported!(
                             connector,
@@ -196,7 +199,9 @@ impl ConnectorValidation for Adyen {
                 | PaymentMethodType::OpenBankingUk
                 | PaymentMethodType::OnlineBankingCzechRepublic
                 | PaymentMethodType::PermataBankTransfer => match capture_method {
-                    enums::CaptureMethod::Automatic => Ok(()),
+                    enums::CaptureMethod::Automatic | enums::CaptureMethod::SequentialAutomatic => {
+                        Ok(())
+                    }
                     enums::CaptureMethod::Manual
                     | enums::CaptureMethod::ManualMultiple
                     | enums::CaptureMethod::Scheduled => {
@@ -236,6 +241,7 @@ impl ConnectorValidation for Adyen {
             },
             None => match capture_method {
                 enums::CaptureMethod::Automatic
+                | enums::CaptureMethod::SequentialAutomatic
                 | enums::CaptureMethod::Manual
                 | enums::CaptureMethod::ManualMultiple => Ok(()),
                 enums::CaptureMethod::Scheduled => {
@@ -154,7 +154,8 @@ fn get_card_data(req: &types::PaymentsAuthorizeRouterData) -> Result<String, Err
 
 fn get_transaction_type(capture_method: Option<enums::CaptureMethod>) -> Result<u8, Error> {
     match capture_method {
-        Some(enums::CaptureMethod::Automatic) | None => Ok(1),
+        Some(enums::CaptureMethod::Automatic | enums::CaptureMethod::SequentialAutomatic)
+        | None => Ok(1),
         Some(enums::CaptureMethod::Manual) => Ok(2),
         _ => Err(errors::ConnectorError::CaptureMethodNotSupported)?,
     }
@@ -225,7 +226,8 @@ fn get_attempt_status(
 ) -> enums::AttemptStatus {
     match response_code {
         0 => match capture_method {
-            Some(enums::CaptureMethod::Automatic) | None => enums::AttemptStatus::Charged,
+            Some(enums::CaptureMethod::Automatic | enums::CaptureMethod::SequentialAutomatic)
+            | None => enums::AttemptStatus::Charged,
             Some(enums::CaptureMethod::Manual) => enums::AttemptStatus::Authorized,
             _ => enums::AttemptStatus::Pending,
         },
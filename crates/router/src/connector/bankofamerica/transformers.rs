@@ -530,6 +530,7 @@ impl From<CardIssuer> for String {
             CardIssuer::DinersClub => "005",
             CardIssuer::CarteBlanche => "006",
             CardIssuer::JCB => "007",
+            CardIssuer::RuPay => "061",
         };
         card_type.to_string()
     }
@@ -645,7 +646,8 @@ impl
         Ok(Self {
             capture: Some(matches!(
                 item.router_data.request.capture_method,
-                Some(enums::CaptureMethod::Automatic) | None
+                Some(enums::CaptureMethod::Automatic | enums::CaptureMethod::SequentialAutomatic)
+                    | None
             )),
             payment_solution: solution.map(String::from),
             action_list,
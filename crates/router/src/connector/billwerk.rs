@@ -138,7 +138,9 @@ impl ConnectorValidation for Billwerk {
     ) -> CustomResult<(), errors::ConnectorError> {
         let capture_method = capture_method.unwrap_or_default();
         match capture_method {
-            common_enums::CaptureMethod::Automatic | common_enums::CaptureMethod::Manual => Ok(()),
+            common_enums::CaptureMethod::Automatic
+            | common_enums::CaptureMethod::SequentialAutomatic
+            | common_enums::CaptureMethod::Manual => Ok(()),
             common_enums::CaptureMethod::ManualMultiple
             | common_enums::CaptureMethod::Scheduled => Err(
                 super::utils::construct_not_implemented_error_report(capture_method, self.id()),
@@ -415,10 +415,11 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
                 .map(|return_url| format!("{return_url}?status=failure")),
         };
 
-        let capture = matches!(
-            item.router_data.request.capture_method,
-            Some(enums::CaptureMethod::Automatic)
-        );
+        let capture = item
+            .router_data
+            .request
+            .capture_method
+            .map_or(false, enums::CaptureMethod::is_automatic);
 
         let connector_auth = &item.router_data.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
@@ -500,9 +501,7 @@ impl ForeignFrom<(CheckoutPaymentStatus, Option<enums::CaptureMethod>)> for enum
         let (status, capture_method) = item;
         match status {
             CheckoutPaymentStatus::Authorized => {
-                if capture_method == Some(enums::CaptureMethod::Automatic)
-                    || capture_method.is_none()
-                {
+                if capture_method.map_or(true, enums::CaptureMethod::is_automatic) {
                     Self::Pending
                 } else {
                     Self::Authorized
@@ -626,9 +625,11 @@ fn get_connector_meta(
     capture_method: enums::CaptureMethod,
 ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
     match capture_method {
-        enums::CaptureMethod::Automatic => Ok(serde_json::json!(CheckoutMeta {
-            psync_flow: CheckoutPaymentIntent::Capture,
-        })),
+        enums::CaptureMethod::Automatic | enums::CaptureMethod::SequentialAutomatic => {
+            Ok(serde_json::json!(CheckoutMeta {
+                psync_flow: CheckoutPaymentIntent::Capture,
+            }))
+        }
         enums::CaptureMethod::Manual | enums::CaptureMethod::ManualMultiple => {
             Ok(serde_json::json!(CheckoutMeta {
                 psync_flow: CheckoutPaymentIntent::Authorize,
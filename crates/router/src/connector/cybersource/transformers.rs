@@ -877,7 +877,8 @@ impl
         Ok(Self {
             capture: Some(matches!(
                 item.router_data.request.capture_method,
-                Some(enums::CaptureMethod::Automatic) | None
+                Some(enums::CaptureMethod::Automatic | enums::CaptureMethod::SequentialAutomatic)
+                    | None
             )),
             payment_solution: solution.map(String::from),
             action_list,
@@ -990,7 +991,8 @@ impl
         Ok(Self {
             capture: Some(matches!(
                 item.router_data.request.capture_method,
-                Some(enums::CaptureMethod::Automatic) | None
+                Some(enums::CaptureMethod::Automatic | enums::CaptureMethod::SequentialAutomatic)
+                    | None
             )),
             payment_solution: solution.map(String::from),
             action_list,
@@ -169,10 +169,11 @@ impl TryFrom<&DatatransRouterData<&types::PaymentsAuthorizeRouterData>>
                     expiry_year: req_card.get_card_expiry_year_2_digit()?,
                 },
                 refno: item.router_data.connector_request_reference_id.clone(),
-                auto_settle: matches!(
-                    item.router_data.request.capture_method,
-                    Some(enums::CaptureMethod::Automatic)
-                ),
+                auto_settle: item
+                    .router_data
+                    .request
+                    .capture_method
+                    .map_or(false, enums::CaptureMethod::is_automatic),
             }),
             domain::PaymentMethodData::Wallet(_)
             | domain::PaymentMethodData::PayLater(_)
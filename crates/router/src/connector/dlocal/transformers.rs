@@ -101,10 +101,11 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
         let name = get_payer_name(address);
         match item.router_data.request.payment_method_data {
             domain::PaymentMethodData::Card(ref ccard) => {
-                let should_capture = matches!(
-                    item.router_data.request.capture_method,
-                    Some(enums::CaptureMethod::Automatic)
-                );
+                let should_capture = item
+                    .router_data
+                    .request
+                    .capture_method
+                    .map_or(false, enums::CaptureMethod::is_automatic);
                 let payment_request = Self {
                     amount: item.amount,
                     currency: item.router_data.request.currency,
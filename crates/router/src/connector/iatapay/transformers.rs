@@ -360,6 +360,7 @@ fn get_iatpay_response(
                                 &checkout_methods.redirect.redirect_url,
                             )
                             .change_context(errors::ConnectorError::ResponseHandlingFailed)?,
+                            display_to_timestamp: None,
                         };
                         (
                             Some(qr_code_info.encode_to_value())
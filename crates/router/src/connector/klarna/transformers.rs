@@ -380,14 +380,30 @@ impl From<KlarnaPaymentStatus> for enums::AttemptStatus {
     }
 }
 
-impl<F, T>
-    TryFrom<types::ResponseRouterData<F, KlarnaPsyncResponse, T, types::PaymentsResponseData>>
-    for types::RouterData<F, T, types::PaymentsResponseData>
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            KlarnaPsyncResponse,
+            types::PaymentsSyncData,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, types::PaymentsSyncData, types::PaymentsResponseData>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::ResponseRouterData<F, KlarnaPsyncResponse, T, types::PaymentsResponseData>,
+        item: types::ResponseRouterData<
+            F,
+            KlarnaPsyncResponse,
+            types::PaymentsSyncData,
+            types::PaymentsResponseData,
+        >,
     ) -> Result<Self, Self::Error> {
+        // Keep the capture_id stored by the capture flow, only the order status is refreshed here
+        let connector_meta = serde_json::json!(KlarnaMeta {
+            order_status: Some(item.response.status.clone()),
+            ..KlarnaMeta::from_connector_meta(item.data.request.connector_meta.clone())
+        });
         Ok(Self {
             status: enums::AttemptStatus::from(item.response.status),
             response: Ok(types::PaymentsResponseData::TransactionResponse {
@@ -396,7 +412,7 @@ impl<F, T>
                 ),
                 redirection_data: None,
                 mandate_reference: None,
-                connector_metadata: None,
+                connector_metadata: Some(connector_meta),
                 network_txn_id: None,
                 connector_response_reference_id: item
                     .response
@@ -429,9 +445,16 @@ impl TryFrom<&KlarnaRouterData<&types::PaymentsCaptureRouterData>> for KlarnaCap
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct KlarnaMeta {
     capture_id: Option<String>,
+    order_status: Option<KlarnaPaymentStatus>,
+}
+
+impl KlarnaMeta {
+    fn from_connector_meta(connector_meta: Option<serde_json::Value>) -> Self {
+        utils::to_connector_meta(connector_meta).unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -460,6 +483,7 @@ impl<F>
     ) -> Result<Self, Self::Error> {
         let connector_meta = serde_json::json!(KlarnaMeta {
             capture_id: item.response.capture_id,
+            ..KlarnaMeta::from_connector_meta(item.data.request.connector_meta.clone())
         });
 
         // https://docs.klarna.com/api/ordermanagement/#operation/captureOrder
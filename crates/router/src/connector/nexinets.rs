@@ -150,7 +150,9 @@ impl ConnectorValidation for Nexinets {
     ) -> CustomResult<(), errors::ConnectorError> {
         let capture_method = capture_method.unwrap_or_default();
         match capture_method {
-            enums::CaptureMethod::Automatic | enums::CaptureMethod::Manual => Ok(()),
+            enums::CaptureMethod::Automatic
+            | enums::CaptureMethod::SequentialAutomatic
+            | enums::CaptureMethod::Manual => Ok(()),
             enums::CaptureMethod::ManualMultiple | enums::CaptureMethod::Scheduled => Err(
                 connector_utils::construct_not_implemented_error_report(capture_method, self.id()),
             ),
@@ -227,7 +229,11 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         req: &types::PaymentsAuthorizeRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        let url = if req.request.capture_method == Some(enums::CaptureMethod::Automatic) {
+        let url = if req
+            .request
+            .capture_method
+            .map_or(false, enums::CaptureMethod::is_automatic)
+        {
             format!("{}/orders/debit", self.base_url(connectors))
         } else {
             format!("{}/orders/preauth", self.base_url(connectors))
@@ -368,8 +368,11 @@ impl
                     incremental_authorization_allowed: None,
                     charge_id: None,
                 }),
-                if let Some(diesel_models::enums::CaptureMethod::Automatic) =
-                    item.data.request.capture_method
+                if item
+                    .data
+                    .request
+                    .capture_method
+                    .map_or(false, diesel_models::enums::CaptureMethod::is_automatic)
                 {
                     enums::AttemptStatus::CaptureInitiated
                 } else {
@@ -901,8 +904,11 @@ impl TryFrom<types::PaymentsResponseRouterData<StandardResponse>>
                     incremental_authorization_allowed: None,
                     charge_id: None,
                 }),
-                if let Some(diesel_models::enums::CaptureMethod::Automatic) =
-                    item.data.request.capture_method
+                if item
+                    .data
+                    .request
+                    .capture_method
+                    .map_or(false, diesel_models::enums::CaptureMethod::is_automatic)
                 {
                     enums::AttemptStatus::CaptureInitiated
                 } else {
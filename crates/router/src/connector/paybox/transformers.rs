@@ -363,7 +363,8 @@ impl TryFrom<&PayboxRouterData<&types::PaymentsAuthorizeRouterData>> for PayboxP
 
 fn get_transaction_type(capture_method: Option<enums::CaptureMethod>) -> Result<String, Error> {
     match capture_method {
-        Some(enums::CaptureMethod::Automatic) | None => Ok(AUTH_AND_CAPTURE_REQUEST.to_string()),
+        Some(enums::CaptureMethod::Automatic | enums::CaptureMethod::SequentialAutomatic)
+        | None => Ok(AUTH_AND_CAPTURE_REQUEST.to_string()),
         Some(enums::CaptureMethod::Manual) => Ok(AUTH_REQUEST.to_string()),
         _ => Err(errors::ConnectorError::CaptureMethodNotSupported)?,
     }
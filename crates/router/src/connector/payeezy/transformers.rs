@@ -65,7 +65,8 @@ impl TryFrom<utils::CardIssuer> for PayeezyCardType {
             utils::CardIssuer::Maestro
             | utils::CardIssuer::DinersClub
             | utils::CardIssuer::JCB
-            | utils::CardIssuer::CarteBlanche => Err(errors::ConnectorError::NotImplemented(
+            | utils::CardIssuer::CarteBlanche
+            | utils::CardIssuer::RuPay => Err(errors::ConnectorError::NotImplemented(
                 utils::get_unimplemented_payment_method_error_message("Payeezy"),
             ))?,
         }
@@ -206,9 +207,10 @@ fn get_transaction_type_and_stored_creds(
                 Some(diesel_models::enums::CaptureMethod::Manual) => {
                     Ok((PayeezyTransactionType::Authorize, None))
                 }
-                Some(diesel_models::enums::CaptureMethod::Automatic) => {
-                    Ok((PayeezyTransactionType::Purchase, None))
-                }
+                Some(
+                    diesel_models::enums::CaptureMethod::Automatic
+                    | diesel_models::enums::CaptureMethod::SequentialAutomatic,
+                ) => Ok((PayeezyTransactionType::Purchase, None)),
 
                 Some(diesel_models::enums::CaptureMethod::ManualMultiple)
                 | Some(diesel_models::enums::CaptureMethod::Scheduled)
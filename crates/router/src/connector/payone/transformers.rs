@@ -197,7 +197,8 @@ impl TryFrom<CardIssuer> for PaymentProductId {
             | CardIssuer::Discover
             | CardIssuer::DinersClub
             | CardIssuer::JCB
-            | CardIssuer::CarteBlanche => Err(errors::ConnectorError::NotImplemented(
+            | CardIssuer::CarteBlanche
+            | CardIssuer::RuPay => Err(errors::ConnectorError::NotImplemented(
                 get_unimplemented_payment_method_error_message("payone"),
             )
             .into()),
@@ -1,5 +1,6 @@
 use common_enums::Currency;
 use common_utils::types::FloatMajorUnit;
+use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 
@@ -74,8 +75,13 @@ pub struct PlaidLinkTokenRequest {
     payment_initiation: PlaidPaymentInitiation,
     redirect_uri: Option<String>,
     android_package_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hosted_link: Option<PlaidHostedLink>,
 }
 
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct PlaidHostedLink {}
+
 #[derive(Default, Debug, Serialize, Deserialize)]
 pub struct User {
     pub client_user_id: String,
@@ -175,10 +181,11 @@ impl TryFrom<&types::PaymentsPostProcessingRouterData> for PlaidLinkTokenRequest
                         },
                     )?;
 
-                    let (is_android, is_ios) = match platform {
-                        common_enums::ClientPlatform::Android => (true, false),
-                        common_enums::ClientPlatform::Ios => (false, true),
-                        _ => (false, false),
+                    let (is_android, is_ios, is_web) = match platform {
+                        common_enums::ClientPlatform::Android => (true, false, false),
+                        common_enums::ClientPlatform::Ios => (false, true, false),
+                        common_enums::ClientPlatform::Web => (false, false, true),
+                        common_enums::ClientPlatform::Unknown => (false, false, false),
                     };
 
                     Ok(Self {
@@ -225,6 +232,8 @@ impl TryFrom<&types::PaymentsPostProcessingRouterData> for PlaidLinkTokenRequest
                         } else {
                             None
                         },
+                        // Web clients are sent to the plaid hosted link page to give the consent
+                        hosted_link: is_web.then_some(PlaidHostedLink {}),
                     })
                 }
             },
@@ -335,6 +344,9 @@ impl<F, T>
 #[derive(Default, Debug, Serialize, Deserialize)]
 pub struct PlaidLinkTokenResponse {
     link_token: String,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    expiration: Option<time::PrimitiveDateTime>,
+    hosted_link_url: Option<url::Url>,
 }
 
 impl<F, T>
@@ -345,13 +357,30 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, PlaidLinkTokenResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
+        let connector_metadata = item
+            .response
+            .hosted_link_url
+            .map(|consent_url| {
+                serde_json::to_value(api_models::payments::OpenBankingConsentInstructions {
+                    consent_url,
+                    expires_at: item
+                        .response
+                        .expiration
+                        .map(|expiration| expiration.assume_utc().unix_timestamp()),
+                })
+            })
+            .transpose()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
         let session_token = Some(api::OpenBankingSessionToken {
             open_banking_session_token: item.response.link_token,
         });
 
         Ok(Self {
             status: enums::AttemptStatus::AuthenticationPending,
-            response: Ok(types::PaymentsResponseData::PostProcessingResponse { session_token }),
+            response: Ok(types::PaymentsResponseData::PostProcessingResponse {
+                session_token,
+                connector_metadata,
+            }),
             ..item.data
         })
     }
@@ -104,7 +104,10 @@ impl TryFrom<&RapydRouterData<&types::PaymentsAuthorizeRouterData>> for RapydPay
                 (
                     Some(matches!(
                         item.router_data.request.capture_method,
-                        Some(enums::CaptureMethod::Automatic) | None
+                        Some(
+                            enums::CaptureMethod::Automatic
+                                | enums::CaptureMethod::SequentialAutomatic
+                        ) | None
                     )),
                     Some(payment_method_options),
                 )
@@ -16,7 +16,7 @@ use common_utils::{
     ext_traits::StringExt,
     id_type,
     pii::{self, Email, IpAddress},
-    types::{AmountConvertor, MinorUnit},
+    types::{AmountConvertor, MinorUnit, StringMajorUnitForConnector},
 };
 use diesel_models::enums;
 use error_stack::{report, ResultExt};
@@ -28,7 +28,7 @@ use hyperswitch_domain_models::{
         SyncIntegrityObject,
     },
 };
-use masking::{ExposeInterface, Secret};
+use masking::{ExposeInterface, Maskable, Secret};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::Serializer;
@@ -184,6 +184,36 @@ where
     }
 }
 
+/// Normalizes a locale such as `en_us` or an `Accept-Language` value such as `en-US,en;q=0.9` to
+/// a BCP-47 tag, using the first language when several are given
+pub fn normalize_language_tag(locale: &str) -> Option<String> {
+    let language = locale.split([',', ';']).next()?.trim();
+    let mut subtags = language
+        .split(['-', '_'])
+        .filter(|subtag| !subtag.is_empty());
+    let primary_language = subtags.next()?;
+    if primary_language == "*" {
+        return None;
+    }
+
+    let mut tag = primary_language.to_ascii_lowercase();
+    for subtag in subtags {
+        tag.push('-');
+        match subtag.len() {
+            // Script, e.g. `zh-Hant`
+            4 if subtag.chars().all(|c| c.is_ascii_alphabetic()) => {
+                let (first, rest) = subtag.split_at(1);
+                tag.push_str(&first.to_ascii_uppercase());
+                tag.push_str(&rest.to_ascii_lowercase());
+            }
+            // Region, e.g. `en-US`
+            2 => tag.push_str(&subtag.to_ascii_uppercase()),
+            _ => tag.push_str(&subtag.to_ascii_lowercase()),
+        }
+    }
+    Some(tag)
+}
+
 pub const SELECTED_PAYMENT_METHOD: &str = "Selected payment method";
 
 pub fn get_unimplemented_payment_method_error_message(connector: &str) -> String {
@@ -666,7 +696,9 @@ impl PaymentsPreProcessingData for types::PaymentsPreProcessingData {
 
     fn is_auto_capture(&self) -> Result<bool, Error> {
         match self.capture_method {
-            Some(enums::CaptureMethod::Automatic) | None => Ok(true),
+            Some(enums::CaptureMethod::Automatic)
+            | Some(enums::CaptureMethod::SequentialAutomatic)
+            | None => Ok(true),
             Some(enums::CaptureMethod::Manual) => Ok(false),
             Some(_) => Err(errors::ConnectorError::CaptureMethodNotSupported.into()),
         }
@@ -710,9 +742,126 @@ impl PaymentsPreProcessingData for types::PaymentsPreProcessingData {
     }
 }
 
+/// Uniform accessor for the amount of a connector request, in the minor unit of the currency
+pub trait ConnectorRequestAmount {
+    fn get_minor_amount(&self) -> Result<MinorUnit, Error>;
+}
+
+impl ConnectorRequestAmount for types::PaymentsAuthorizeData {
+    fn get_minor_amount(&self) -> Result<MinorUnit, Error> {
+        Ok(self.minor_amount)
+    }
+}
+
+impl ConnectorRequestAmount for types::PaymentsCaptureData {
+    /// Returns the amount to be captured, not the amount of the payment
+    fn get_minor_amount(&self) -> Result<MinorUnit, Error> {
+        Ok(self.minor_amount_to_capture)
+    }
+}
+
+impl ConnectorRequestAmount for PaymentsCancelData {
+    /// Falls back to the legacy amount
+    fn get_minor_amount(&self) -> Result<MinorUnit, Error> {
+        self.minor_amount
+            .or(self.amount.map(MinorUnit::new))
+            .ok_or_else(missing_field_err("amount"))
+    }
+}
+
+impl ConnectorRequestAmount for types::SetupMandateRequestData {
+    /// Falls back to the legacy amount
+    fn get_minor_amount(&self) -> Result<MinorUnit, Error> {
+        self.minor_amount
+            .or(self.amount.map(MinorUnit::new))
+            .ok_or_else(missing_field_err("amount"))
+    }
+}
+
+pub trait BrowserInfoRequestData {
+    fn get_optional_browser_info(&self) -> Option<&BrowserInformation>;
+}
+
+impl BrowserInfoRequestData for types::PaymentsAuthorizeData {
+    fn get_optional_browser_info(&self) -> Option<&BrowserInformation> {
+        self.browser_info.as_ref()
+    }
+}
+
+impl BrowserInfoRequestData for types::PaymentsCaptureData {
+    fn get_optional_browser_info(&self) -> Option<&BrowserInformation> {
+        self.browser_info.as_ref()
+    }
+}
+
+impl BrowserInfoRequestData for PaymentsCancelData {
+    fn get_optional_browser_info(&self) -> Option<&BrowserInformation> {
+        self.browser_info.as_ref()
+    }
+}
+
+impl BrowserInfoRequestData for types::PaymentsPreProcessingData {
+    fn get_optional_browser_info(&self) -> Option<&BrowserInformation> {
+        self.browser_info.as_ref()
+    }
+}
+
+impl BrowserInfoRequestData for types::CompleteAuthorizeData {
+    fn get_optional_browser_info(&self) -> Option<&BrowserInformation> {
+        self.browser_info.as_ref()
+    }
+}
+
+impl BrowserInfoRequestData for types::SetupMandateRequestData {
+    fn get_optional_browser_info(&self) -> Option<&BrowserInformation> {
+        self.browser_info.as_ref()
+    }
+}
+
+impl BrowserInfoRequestData for types::PaymentMethodTokenizationData {
+    fn get_optional_browser_info(&self) -> Option<&BrowserInformation> {
+        self.browser_info.as_ref()
+    }
+}
+
+impl BrowserInfoRequestData for types::RefundsData {
+    fn get_optional_browser_info(&self) -> Option<&BrowserInformation> {
+        self.browser_info.as_ref()
+    }
+}
+
+pub trait RouterDataLanguage {
+    /// Preferred language of the customer as a BCP-47 tag, taken from the browser info of the
+    /// request and falling back to the locale header
+    fn get_optional_language(&self) -> Option<String>;
+}
+
+impl<Flow, Request, Response> RouterDataLanguage for types::RouterData<Flow, Request, Response>
+where
+    Request: BrowserInfoRequestData,
+{
+    fn get_optional_language(&self) -> Option<String> {
+        self.request
+            .get_optional_browser_info()
+            .and_then(|browser_info| browser_info.language.as_deref())
+            .and_then(normalize_language_tag)
+            .or_else(|| {
+                self.header_payload
+                    .as_ref()
+                    .and_then(|header_payload| header_payload.locale.as_deref())
+                    .and_then(normalize_language_tag)
+            })
+    }
+}
+
 pub trait PaymentsCaptureRequestData {
     fn is_multiple_capture(&self) -> bool;
     fn get_browser_info(&self) -> Result<BrowserInformation, Error>;
+    fn get_shipping_cost(&self) -> Result<MinorUnit, Error>;
+    /// Amount to capture in the base unit of the currency, formatted with the number of
+    /// decimals of the currency (none, two or three)
+    fn get_amount_to_capture_base_unit(&self) -> Result<String, Error>;
+    fn get_merchant_order_reference_id(&self) -> Result<String, Error>;
 }
 
 impl PaymentsCaptureRequestData for types::PaymentsCaptureData {
@@ -724,6 +873,23 @@ impl PaymentsCaptureRequestData for types::PaymentsCaptureData {
             .clone()
             .ok_or_else(missing_field_err("browser_info"))
     }
+    fn get_shipping_cost(&self) -> Result<MinorUnit, Error> {
+        self.shipping_cost
+            .ok_or_else(missing_field_err("shipping_cost"))
+    }
+    fn get_amount_to_capture_base_unit(&self) -> Result<String, Error> {
+        convert_amount(
+            &StringMajorUnitForConnector,
+            self.minor_amount_to_capture,
+            self.currency,
+        )
+        .map(|amount| amount.get_amount_as_string())
+    }
+    fn get_merchant_order_reference_id(&self) -> Result<String, Error> {
+        self.merchant_order_reference_id
+            .clone()
+            .ok_or_else(missing_field_err("merchant_order_reference_id"))
+    }
 }
 
 pub trait RevokeMandateRequestData {
@@ -800,7 +966,9 @@ impl PaymentMethodTokenizationRequestData for types::PaymentMethodTokenizationDa
 impl PaymentsAuthorizeRequestData for types::PaymentsAuthorizeData {
     fn is_auto_capture(&self) -> Result<bool, Error> {
         match self.capture_method {
-            Some(enums::CaptureMethod::Automatic) | None => Ok(true),
+            Some(enums::CaptureMethod::Automatic)
+            | Some(enums::CaptureMethod::SequentialAutomatic)
+            | None => Ok(true),
             Some(enums::CaptureMethod::Manual) => Ok(false),
             Some(_) => Err(errors::ConnectorError::CaptureMethodNotSupported.into()),
         }
@@ -986,6 +1154,7 @@ pub trait BrowserInformationData {
     fn get_java_enabled(&self) -> Result<bool, Error>;
     fn get_java_script_enabled(&self) -> Result<bool, Error>;
     fn get_ip_address(&self) -> Result<Secret<String, IpAddress>, Error>;
+    fn get_ip_address_as_ip(&self) -> Result<std::net::IpAddr, Error>;
 }
 
 impl BrowserInformationData for BrowserInformation {
@@ -995,6 +1164,10 @@ impl BrowserInformationData for BrowserInformation {
             .ok_or_else(missing_field_err("browser_info.ip_address"))?;
         Ok(Secret::new(ip_address.to_string()))
     }
+    fn get_ip_address_as_ip(&self) -> Result<std::net::IpAddr, Error> {
+        self.ip_address
+            .ok_or_else(missing_field_err("browser_info.ip_address"))
+    }
     fn get_accept_header(&self) -> Result<String, Error> {
         self.accept_header
             .clone()
@@ -1047,7 +1220,9 @@ pub trait PaymentsCompleteAuthorizeRequestData {
 impl PaymentsCompleteAuthorizeRequestData for types::CompleteAuthorizeData {
     fn is_auto_capture(&self) -> Result<bool, Error> {
         match self.capture_method {
-            Some(enums::CaptureMethod::Automatic) | None => Ok(true),
+            Some(enums::CaptureMethod::Automatic)
+            | Some(enums::CaptureMethod::SequentialAutomatic)
+            | None => Ok(true),
             Some(enums::CaptureMethod::Manual) => Ok(false),
             Some(_) => Err(errors::ConnectorError::CaptureMethodNotSupported.into()),
         }
@@ -1087,14 +1262,22 @@ impl PaymentsCompleteAuthorizeRequestData for types::CompleteAuthorizeData {
 pub trait PaymentsSyncRequestData {
     fn is_auto_capture(&self) -> Result<bool, Error>;
     fn get_connector_transaction_id(&self) -> CustomResult<String, errors::ConnectorError>;
+    fn get_connector_customer_id(&self) -> Result<String, Error>;
+    fn get_merchant_order_reference_id(&self) -> Result<String, Error>;
 }
 
 impl PaymentsSyncRequestData for types::PaymentsSyncData {
     fn is_auto_capture(&self) -> Result<bool, Error> {
         match self.capture_method {
-            Some(enums::CaptureMethod::Automatic) | None => Ok(true),
-            Some(enums::CaptureMethod::Manual) => Ok(false),
-            Some(_) => Err(errors::ConnectorError::CaptureMethodNotSupported.into()),
+            Some(enums::CaptureMethod::Automatic)
+            | Some(enums::CaptureMethod::SequentialAutomatic)
+            | None => Ok(true),
+            Some(enums::CaptureMethod::Manual) | Some(enums::CaptureMethod::ManualMultiple) => {
+                Ok(false)
+            }
+            Some(enums::CaptureMethod::Scheduled) => {
+                Err(errors::ConnectorError::CaptureMethodNotSupported.into())
+            }
         }
     }
     fn get_connector_transaction_id(&self) -> CustomResult<String, errors::ConnectorError> {
@@ -1107,6 +1290,16 @@ impl PaymentsSyncRequestData for types::PaymentsSyncData {
             .change_context(errors::ConnectorError::MissingConnectorTransactionID)?,
         }
     }
+    fn get_connector_customer_id(&self) -> Result<String, Error> {
+        self.connector_customer
+            .clone()
+            .ok_or_else(missing_field_err("connector_customer_id"))
+    }
+    fn get_merchant_order_reference_id(&self) -> Result<String, Error> {
+        self.merchant_order_reference_id
+            .clone()
+            .ok_or_else(missing_field_err("merchant_order_reference_id"))
+    }
 }
 
 #[cfg(feature = "payouts")]
@@ -1174,6 +1367,7 @@ pub trait PaymentsCancelRequestData {
     fn get_currency(&self) -> Result<enums::Currency, Error>;
     fn get_cancellation_reason(&self) -> Result<String, Error>;
     fn get_browser_info(&self) -> Result<BrowserInformation, Error>;
+    fn get_connector_customer_id(&self) -> Result<String, Error>;
 }
 
 impl PaymentsCancelRequestData for PaymentsCancelData {
@@ -1193,10 +1387,17 @@ impl PaymentsCancelRequestData for PaymentsCancelData {
             .clone()
             .ok_or_else(missing_field_err("browser_info"))
     }
+    fn get_connector_customer_id(&self) -> Result<String, Error> {
+        self.connector_customer
+            .clone()
+            .ok_or_else(missing_field_err("connector_customer_id"))
+    }
 }
 
 pub trait RefundsRequestData {
     fn get_connector_refund_id(&self) -> Result<String, Error>;
+    /// Connector refund id, absent until the connector has responded to the refund create call
+    fn get_optional_connector_refund_id(&self) -> Option<String>;
     fn get_webhook_url(&self) -> Result<String, Error>;
     fn get_browser_info(&self) -> Result<BrowserInformation, Error>;
     fn get_connector_metadata(&self) -> Result<serde_json::Value, Error>;
@@ -1210,6 +1411,9 @@ impl RefundsRequestData for types::RefundsData {
             .get_required_value("connector_refund_id")
             .change_context(errors::ConnectorError::MissingConnectorTransactionID)
     }
+    fn get_optional_connector_refund_id(&self) -> Option<String> {
+        self.connector_refund_id.clone()
+    }
     fn get_webhook_url(&self) -> Result<String, Error> {
         self.webhook_url
             .clone()
@@ -1323,6 +1527,11 @@ static CARD_REGEX: Lazy<HashMap<CardIssuer, Result<Regex, regex::Error>>> = Lazy
         Regex::new(r"^(3(?:088|096|112|158|337|5(?:2[89]|[3-8][0-9]))\d{12})$"),
     );
     map.insert(CardIssuer::CarteBlanche, Regex::new(r"^389[0-9]{11}$"));
+    // 6011 is left out of the 60 range as it belongs to Discover
+    map.insert(
+        CardIssuer::RuPay,
+        Regex::new(r"^(60(?:[02-9][0-9]|1[02-9])[0-9]{12}|652[12][0-9]{12}|508[0-9]{13})$"),
+    );
     map
 });
 
@@ -1336,11 +1545,36 @@ pub enum CardIssuer {
     DinersClub,
     JCB,
     CarteBlanche,
+    RuPay,
+}
+
+impl From<CardIssuer> for api_models::enums::CardNetwork {
+    fn from(card_issuer: CardIssuer) -> Self {
+        match card_issuer {
+            CardIssuer::AmericanExpress => Self::AmericanExpress,
+            CardIssuer::Master => Self::Mastercard,
+            CardIssuer::Maestro => Self::Maestro,
+            CardIssuer::Visa => Self::Visa,
+            CardIssuer::Discover => Self::Discover,
+            CardIssuer::DinersClub | CardIssuer::CarteBlanche => Self::DinersClub,
+            CardIssuer::JCB => Self::JCB,
+            CardIssuer::RuPay => Self::RuPay,
+        }
+    }
 }
 
 pub trait CardData {
     fn get_card_expiry_year_2_digit(&self) -> Result<Secret<String>, errors::ConnectorError>;
     fn get_card_issuer(&self) -> Result<CardIssuer, Error>;
+    /// Network to process the card on, the network selected for co-badged cards takes precedence
+    /// over the one derived from the card number
+    fn get_card_network(&self) -> Result<api_models::enums::CardNetwork, Error>;
+    /// First 6 digits of the card number
+    fn get_card_isin(&self) -> Secret<String>;
+    /// First 8 digits of the card number
+    fn get_extended_bin(&self) -> Secret<String>;
+    /// Whether the card number is one of the well known test card numbers
+    fn is_test_card(&self) -> bool;
     fn get_card_expiry_month_year_2_digit_with_delimiter(
         &self,
         delimiter: String,
@@ -1351,6 +1585,17 @@ pub trait CardData {
     fn get_expiry_date_as_yymm(&self) -> Result<Secret<String>, errors::ConnectorError>;
     fn get_expiry_month_as_i8(&self) -> Result<Secret<i8>, Error>;
     fn get_expiry_year_as_i32(&self) -> Result<Secret<i32>, Error>;
+    fn get_card_holder_name(&self) -> Result<Secret<String>, Error>;
+    /// Card holder name, falling back to the billing name when the card does not carry one
+    fn get_card_holder_name_or_billing_name<T: RouterData>(
+        &self,
+        router_data: &T,
+    ) -> Result<Secret<String>, Error> {
+        self.get_card_holder_name()
+            .ok()
+            .or_else(|| router_data.get_optional_billing_full_name())
+            .ok_or_else(missing_field_err("card.card_holder_name"))
+    }
 }
 
 #[cfg(feature = "payouts")]
@@ -1367,6 +1612,19 @@ impl CardData for payouts::CardPayout {
     fn get_card_issuer(&self) -> Result<CardIssuer, Error> {
         get_card_issuer(self.card_number.peek())
     }
+    fn get_card_network(&self) -> Result<api_models::enums::CardNetwork, Error> {
+        self.get_card_issuer()
+            .map(api_models::enums::CardNetwork::from)
+    }
+    fn get_card_isin(&self) -> Secret<String> {
+        Secret::new(self.card_number.get_card_isin())
+    }
+    fn get_extended_bin(&self) -> Secret<String> {
+        Secret::new(self.card_number.get_extended_card_bin())
+    }
+    fn is_test_card(&self) -> bool {
+        is_test_card_number(self.card_number.peek())
+    }
     fn get_card_expiry_month_year_2_digit_with_delimiter(
         &self,
         delimiter: String,
@@ -1425,6 +1683,11 @@ impl CardData for payouts::CardPayout {
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)
             .map(Secret::new)
     }
+    fn get_card_holder_name(&self) -> Result<Secret<String>, Error> {
+        self.card_holder_name
+            .clone()
+            .ok_or_else(missing_field_err("card.card_holder_name"))
+    }
 }
 
 impl CardData for domain::Card {
@@ -1440,6 +1703,23 @@ impl CardData for domain::Card {
     fn get_card_issuer(&self) -> Result<CardIssuer, Error> {
         get_card_issuer(self.card_number.peek())
     }
+    fn get_card_network(&self) -> Result<api_models::enums::CardNetwork, Error> {
+        match self.card_network.clone() {
+            Some(card_network) => Ok(card_network),
+            None => self
+                .get_card_issuer()
+                .map(api_models::enums::CardNetwork::from),
+        }
+    }
+    fn get_card_isin(&self) -> Secret<String> {
+        Secret::new(self.card_number.get_card_isin())
+    }
+    fn get_extended_bin(&self) -> Secret<String> {
+        Secret::new(self.card_number.get_extended_card_bin())
+    }
+    fn is_test_card(&self) -> bool {
+        is_test_card_number(self.card_number.peek())
+    }
     fn get_card_expiry_month_year_2_digit_with_delimiter(
         &self,
         delimiter: String,
@@ -1498,15 +1778,26 @@ impl CardData for domain::Card {
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)
             .map(Secret::new)
     }
+    fn get_card_holder_name(&self) -> Result<Secret<String>, Error> {
+        self.card_holder_name
+            .clone()
+            .ok_or_else(missing_field_err("card.card_holder_name"))
+    }
 }
 
 #[track_caller]
 fn get_card_issuer(card_number: &str) -> Result<CardIssuer, Error> {
+    // Card numbers may be entered with unicode spaces (e.g. non-breaking spaces from mobile
+    // keyboards) or dashes as separators, strip them before matching
+    let card_number: String = card_number
+        .chars()
+        .filter(|c| !c.is_whitespace() && !is_dash(*c))
+        .collect();
     for (k, v) in CARD_REGEX.iter() {
         let regex: Regex = v
             .clone()
             .change_context(errors::ConnectorError::RequestEncodingFailed)?;
-        if regex.is_match(card_number) {
+        if regex.is_match(&card_number) {
             return Ok(*k);
         }
     }
@@ -1514,6 +1805,19 @@ fn get_card_issuer(card_number: &str) -> Result<CardIssuer, Error> {
         errors::ConnectorError::NotImplemented("Card Type".into()),
     ))
 }
+
+fn is_dash(c: char) -> bool {
+    matches!(
+        c,
+        '-' | '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{FE63}' | '\u{FF0D}'
+    )
+}
+
+fn is_test_card_number(card_number: &str) -> bool {
+    let card_number: String = card_number.chars().filter(|c| !c.is_whitespace()).collect();
+    consts::TEST_CARD_NUMBERS.contains(&card_number.as_str())
+}
+
 pub trait WalletData {
     fn get_wallet_token(&self) -> Result<Secret<String>, Error>;
     fn get_wallet_token_as_json<T>(&self, wallet_name: String) -> Result<T, Error>
@@ -1667,6 +1971,9 @@ pub trait AddressDetailsData {
     fn get_line2(&self) -> Result<&Secret<String>, Error>;
     fn get_state(&self) -> Result<&Secret<String>, Error>;
     fn get_zip(&self) -> Result<&Secret<String>, Error>;
+    /// Zip in the format expected for AVS matching, alphanumeric postal codes are uppercased and
+    /// stripped of spaces while numeric zips are returned as is
+    fn get_normalized_zip(&self) -> Result<Secret<String>, Error>;
     fn get_country(&self) -> Result<&api_models::enums::CountryAlpha2, Error>;
     fn get_combined_address_line(&self) -> Result<Secret<String>, Error>;
     fn to_state_code(&self) -> Result<Secret<String>, Error>;
@@ -1730,6 +2037,20 @@ impl AddressDetailsData for api::AddressDetails {
             .ok_or_else(missing_field_err("address.zip"))
     }
 
+    fn get_normalized_zip(&self) -> Result<Secret<String>, Error> {
+        let zip = self.get_zip()?.peek();
+        if zip.chars().any(|character| character.is_ascii_alphabetic()) {
+            Ok(Secret::new(
+                zip.chars()
+                    .filter(|character| !character.is_whitespace())
+                    .collect::<String>()
+                    .to_uppercase(),
+            ))
+        } else {
+            Ok(Secret::new(zip.to_owned()))
+        }
+    }
+
     fn get_country(&self) -> Result<&api_models::enums::CountryAlpha2, Error> {
         self.country
             .as_ref()
@@ -1890,6 +2211,16 @@ where
     json.parse_value(std::any::type_name::<T>()).switch()
 }
 
+/// Builds an `application/x-www-form-urlencoded` body from the given key-value pairs.
+/// The encoded body is wrapped in a `Secret` so that masked values are not leaked through logs.
+pub fn to_form_urlencoded(pairs: &[(&str, Maskable<String>)]) -> Secret<String> {
+    let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+    for (key, value) in pairs {
+        serializer.append_pair(key, &value.clone().into_inner());
+    }
+    Secret::new(serializer.finish())
+}
+
 pub fn base64_decode(data: String) -> Result<Vec<u8>, Error> {
     consts::BASE64_ENGINE
         .decode(data)
@@ -2277,6 +2608,108 @@ pub fn validate_currency(
     Ok(())
 }
 
+pub fn validate_card_expiry(month: &str, year: &str) -> Result<(), Error> {
+    month
+        .trim()
+        .parse::<u8>()
+        .ok()
+        .filter(|month| (1..=12).contains(month))
+        .ok_or(errors::ConnectorError::InvalidDataFormat {
+            field_name: "card_exp_month",
+        })?;
+    let year = year.trim();
+    // Two digit years are normalized to the current century before the range check
+    match year.len() {
+        2 => year.parse::<u16>().ok().map(|year| 2000 + year),
+        4 => year.parse::<u16>().ok(),
+        _ => None,
+    }
+    .filter(|year| (2000..=2099).contains(year))
+    .ok_or(errors::ConnectorError::InvalidDataFormat {
+        field_name: "card_exp_year",
+    })?;
+    Ok(())
+}
+
+pub fn convert_to_settlement_currency(
+    amount: MinorUnit,
+    from: enums::Currency,
+    to: enums::Currency,
+    rate: f64,
+) -> Result<MinorUnit, Error> {
+    use rust_decimal::prelude::{Decimal, FromPrimitive, RoundingStrategy, ToPrimitive};
+
+    let rate = Decimal::from_f64(rate)
+        .filter(|rate| rate.is_sign_positive() && !rate.is_zero())
+        .ok_or(errors::ConnectorError::InvalidDataFormat {
+            field_name: "exchange_rate",
+        })?;
+    let from_scale = Decimal::from(10_i64.pow(from.number_of_digits_after_decimal_point().into()));
+    let to_scale = Decimal::from(10_i64.pow(to.number_of_digits_after_decimal_point().into()));
+
+    Decimal::from(amount.get_amount_as_i64())
+        .checked_mul(rate)
+        .and_then(|converted_amount| converted_amount.checked_mul(to_scale))
+        .and_then(|converted_amount| converted_amount.checked_div(from_scale))
+        .and_then(|converted_amount| {
+            converted_amount
+                .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+                .to_i64()
+        })
+        .map(MinorUnit::new)
+        .ok_or(errors::ConnectorError::AmountConversionFailed.into())
+}
+
+pub fn redact_url_query(url: &str, sensitive_params: &[&str]) -> String {
+    let (url_without_fragment, fragment) = match url.split_once('#') {
+        Some((url_without_fragment, fragment)) => (url_without_fragment, Some(fragment)),
+        None => (url, None),
+    };
+    let mut redacted_url = match url_without_fragment.split_once('?') {
+        Some((base_url, query)) => {
+            let redacted_query = query
+                .split('&')
+                .map(|pair| match pair.split_once('=') {
+                    Some((key, _))
+                        if sensitive_params
+                            .iter()
+                            .any(|param| param.eq_ignore_ascii_case(key)) =>
+                    {
+                        format!("{key}=*****")
+                    }
+                    _ => pair.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("&");
+            format!("{base_url}?{redacted_query}")
+        }
+        None => url_without_fragment.to_string(),
+    };
+    if let Some(fragment) = fragment {
+        redacted_url.push('#');
+        redacted_url.push_str(fragment);
+    }
+    redacted_url
+}
+
+pub fn dedupe_address_lines(addr: &mut api::AddressDetails) {
+    let line1 = addr
+        .line1
+        .as_ref()
+        .map(|line1| line1.peek().trim().to_owned());
+    let is_duplicate_of_line1 = |line: &Option<Secret<String>>| {
+        line.as_ref()
+            .zip(line1.as_ref())
+            .is_some_and(|(line, line1)| line.peek().trim() == line1)
+    };
+    if is_duplicate_of_line1(&addr.line2) {
+        addr.line2 = None;
+    }
+    if is_duplicate_of_line1(&addr.line3) {
+        addr.line3 = None;
+    }
+}
+
 pub fn get_timestamp_in_milliseconds(datetime: &PrimitiveDateTime) -> i64 {
     let utc_datetime = datetime.assume_utc();
     utc_datetime.unix_timestamp() * 1000
@@ -3019,3 +3452,838 @@ impl NetworkTokenData for domain::NetworkTokenData {
         Secret::new(year)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::marker::PhantomData;
+
+    use super::*;
+
+    fn get_router_data_with_address<Req>(
+        request: Req,
+        address: types::PaymentAddress,
+    ) -> types::RouterData<api::Authorize, Req, types::PaymentsResponseData> {
+        types::RouterData {
+            flow: PhantomData,
+            merchant_id: id_type::MerchantId::default(),
+            customer_id: None,
+            connector_customer: None,
+            connector: "dummyconnector".to_string(),
+            payment_id: "pay_123".to_string(),
+            attempt_id: "pay_123_1".to_string(),
+            status: enums::AttemptStatus::default(),
+            payment_method: enums::PaymentMethod::Card,
+            connector_auth_type: types::ConnectorAuthType::default(),
+            description: None,
+            return_url: None,
+            address,
+            auth_type: enums::AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            connector_wallets_details: None,
+            amount_captured: None,
+            access_token: None,
+            session_token: None,
+            reference_id: None,
+            payment_method_token: None,
+            recurring_mandate_payment_data: None,
+            preprocessing_id: None,
+            payment_method_balance: None,
+            connector_api_version: None,
+            request,
+            response: Err(types::ErrorResponse::default()),
+            connector_request_reference_id: "pay_123_1".to_string(),
+            #[cfg(feature = "payouts")]
+            payout_method_data: None,
+            #[cfg(feature = "payouts")]
+            quote_id: None,
+            test_mode: None,
+            connector_http_status_code: None,
+            external_latency: None,
+            connector_request_id: None,
+            apple_pay_flow: None,
+            frm_metadata: None,
+            dispute_id: None,
+            refund_id: None,
+            connector_response: None,
+            payment_method_status: None,
+            minor_amount_captured: None,
+            integrity_check: Ok(()),
+            additional_merchant_data: None,
+            header_payload: None,
+        }
+    }
+
+    fn get_billing_address_with_name(
+        first_name: Option<&str>,
+        last_name: Option<&str>,
+    ) -> types::PaymentAddress {
+        let billing = api::Address {
+            address: Some(api::AddressDetails {
+                first_name: first_name.map(|first_name| Secret::new(first_name.to_string())),
+                last_name: last_name.map(|last_name| Secret::new(last_name.to_string())),
+                ..Default::default()
+            }),
+            phone: None,
+            email: None,
+        };
+        types::PaymentAddress::new(None, None, Some(billing), None)
+    }
+
+    fn get_billing_address(email: Option<&str>) -> types::PaymentAddress {
+        let billing = api::Address {
+            address: None,
+            phone: None,
+            email: email.map(|email| Email::from_str(email).unwrap()),
+        };
+        types::PaymentAddress::new(None, None, Some(billing), None)
+    }
+
+    #[test]
+    fn test_to_form_urlencoded_encodes_special_characters() {
+        let body = to_form_urlencoded(&[
+            ("description", Maskable::new_normal("a b&c=d".to_string())),
+            (
+                "email",
+                Maskable::new_normal("john+doe@example.com".to_string()),
+            ),
+        ]);
+        assert_eq!(
+            body.peek(),
+            "description=a+b%26c%3Dd&email=john%2Bdoe%40example.com"
+        );
+    }
+
+    #[test]
+    fn test_to_form_urlencoded_does_not_leak_secrets_in_debug() {
+        let body = to_form_urlencoded(&[
+            ("amount", Maskable::new_normal("1000".to_string())),
+            (
+                "card_number",
+                Maskable::new_masked(Secret::new("4242424242424242".to_string())),
+            ),
+        ]);
+        assert_eq!(body.peek(), "amount=1000&card_number=4242424242424242");
+        assert!(!format!("{body:?}").contains("4242424242424242"));
+    }
+
+    #[test]
+    fn test_sync_request_is_auto_capture() {
+        let get_request = |capture_method| types::PaymentsSyncData {
+            capture_method,
+            ..Default::default()
+        };
+        assert!(get_request(None).is_auto_capture().unwrap());
+        assert!(get_request(Some(enums::CaptureMethod::Automatic))
+            .is_auto_capture()
+            .unwrap());
+        assert!(get_request(Some(enums::CaptureMethod::SequentialAutomatic))
+            .is_auto_capture()
+            .unwrap());
+        assert!(!get_request(Some(enums::CaptureMethod::Manual))
+            .is_auto_capture()
+            .unwrap());
+        assert!(!get_request(Some(enums::CaptureMethod::ManualMultiple))
+            .is_auto_capture()
+            .unwrap());
+        assert!(get_request(Some(enums::CaptureMethod::Scheduled))
+            .is_auto_capture()
+            .is_err());
+    }
+
+    #[test]
+    fn test_sync_request_connector_customer_id() {
+        let request = types::PaymentsSyncData {
+            connector_customer: Some("cus_123".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(request.get_connector_customer_id().unwrap(), "cus_123");
+
+        let request = types::PaymentsSyncData::default();
+        assert!(request.get_connector_customer_id().is_err());
+    }
+
+    #[test]
+    fn test_cancel_request_connector_customer_id() {
+        let request = types::PaymentsCancelData {
+            connector_customer: Some("cus_123".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(request.get_connector_customer_id().unwrap(), "cus_123");
+
+        let request = types::PaymentsCancelData::default();
+        assert!(request.get_connector_customer_id().is_err());
+    }
+
+    #[test]
+    fn test_get_billing_email_when_present() {
+        let router_data =
+            get_router_data_with_address((), get_billing_address(Some("john@example.com")));
+        assert_eq!(
+            router_data.get_billing_email().unwrap().peek(),
+            "john@example.com"
+        );
+        assert_eq!(
+            router_data
+                .get_optional_billing_email()
+                .map(|email| email.peek().to_owned()),
+            Some("john@example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_billing_email_when_absent() {
+        let router_data = get_router_data_with_address((), get_billing_address(None));
+        assert!(router_data.get_billing_email().is_err());
+        assert!(router_data.get_optional_billing_email().is_none());
+
+        let router_data = get_router_data_with_address((), types::PaymentAddress::default());
+        assert!(router_data.get_billing_email().is_err());
+        assert!(router_data.get_optional_billing_email().is_none());
+    }
+
+    #[test]
+    fn test_get_optional_billing_and_shipping_country() {
+        let address = api::Address {
+            address: Some(api::AddressDetails {
+                country: Some(api_models::enums::CountryAlpha2::IN),
+                ..Default::default()
+            }),
+            phone: None,
+            email: None,
+        };
+        let router_data = get_router_data_with_address(
+            (),
+            types::PaymentAddress::new(Some(address.clone()), None, Some(address), None),
+        );
+        assert_eq!(
+            router_data.get_optional_billing_country(),
+            Some(api_models::enums::CountryAlpha2::IN)
+        );
+        assert_eq!(
+            router_data.get_optional_shipping_country(),
+            Some(api_models::enums::CountryAlpha2::IN)
+        );
+
+        let router_data = get_router_data_with_address((), get_billing_address(None));
+        assert_eq!(router_data.get_optional_billing_country(), None);
+        assert_eq!(router_data.get_optional_shipping_country(), None);
+        assert!(router_data.get_billing_country().is_err());
+    }
+
+    #[test]
+    fn test_sync_request_merchant_order_reference_id() {
+        let request = types::PaymentsSyncData {
+            merchant_order_reference_id: Some("order_123".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            request.get_merchant_order_reference_id().unwrap(),
+            "order_123"
+        );
+
+        let request = types::PaymentsSyncData::default();
+        assert!(request.get_merchant_order_reference_id().is_err());
+    }
+
+    #[test]
+    fn test_capture_request_merchant_order_reference_id() {
+        let request = types::PaymentsCaptureData {
+            merchant_order_reference_id: Some("order_123".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            request.get_merchant_order_reference_id().unwrap(),
+            "order_123"
+        );
+
+        let request = types::PaymentsCaptureData::default();
+        assert!(request.get_merchant_order_reference_id().is_err());
+    }
+
+    #[test]
+    fn test_approve_and_reject_request_connector_meta() {
+        #[derive(Debug, serde::Deserialize)]
+        struct ConnectorMeta {
+            transaction_reference: String,
+        }
+
+        let connector_meta = serde_json::json!({ "transaction_reference": "txn_123" });
+        let approve_request = types::PaymentsApproveData {
+            connector_meta: Some(connector_meta.clone()),
+            ..Default::default()
+        };
+        let reject_request = types::PaymentsRejectData {
+            connector_meta: Some(connector_meta),
+            ..Default::default()
+        };
+
+        let approve_meta: ConnectorMeta =
+            to_connector_meta(approve_request.connector_meta).unwrap();
+        let reject_meta: ConnectorMeta = to_connector_meta(reject_request.connector_meta).unwrap();
+        assert_eq!(approve_meta.transaction_reference, "txn_123");
+        assert_eq!(reject_meta.transaction_reference, "txn_123");
+
+        let reject_request = types::PaymentsRejectData::default();
+        assert!(to_connector_meta::<ConnectorMeta>(reject_request.connector_meta).is_err());
+    }
+
+    #[test]
+    fn test_get_amount_to_capture_base_unit() {
+        let request = types::PaymentsCaptureData {
+            minor_amount_to_capture: MinorUnit::new(1500),
+            currency: enums::Currency::JPY,
+            ..Default::default()
+        };
+        assert_eq!(request.get_amount_to_capture_base_unit().unwrap(), "1500");
+
+        let request = types::PaymentsCaptureData {
+            minor_amount_to_capture: MinorUnit::new(12345),
+            currency: enums::Currency::KWD,
+            ..Default::default()
+        };
+        assert_eq!(request.get_amount_to_capture_base_unit().unwrap(), "12.345");
+
+        let request = types::PaymentsCaptureData {
+            minor_amount_to_capture: MinorUnit::new(1050),
+            currency: enums::Currency::USD,
+            ..Default::default()
+        };
+        assert_eq!(request.get_amount_to_capture_base_unit().unwrap(), "10.50");
+    }
+
+    #[test]
+    fn test_validate_card_expiry() {
+        assert!(validate_card_expiry("13", "2025").is_err());
+        assert!(validate_card_expiry("00", "2025").is_err());
+        assert!(validate_card_expiry("2", "2025").is_ok());
+        assert!(validate_card_expiry("02", "25").is_ok());
+        assert!(validate_card_expiry("02", "1999").is_err());
+        assert!(validate_card_expiry("02", "202").is_err());
+    }
+
+    #[test]
+    fn test_get_card_network_with_selected_network() {
+        let card = domain::Card {
+            card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
+            card_network: Some(api_models::enums::CardNetwork::CartesBancaires),
+            ..Default::default()
+        };
+        assert_eq!(
+            card.get_card_network().unwrap(),
+            api_models::enums::CardNetwork::CartesBancaires
+        );
+        assert!(matches!(card.get_card_issuer().unwrap(), CardIssuer::Visa));
+    }
+
+    #[test]
+    fn test_get_card_issuer_for_rupay_cards() {
+        for card_number in [
+            "6070000000000000",
+            "6010000000000000",
+            "6521000000000000",
+            "6522000000000000",
+            "5080000000000000",
+        ] {
+            assert!(matches!(
+                get_card_issuer(card_number).unwrap(),
+                CardIssuer::RuPay
+            ));
+        }
+        assert!(matches!(
+            get_card_issuer("6011111111111117").unwrap(),
+            CardIssuer::Discover
+        ));
+        assert!(matches!(
+            get_card_issuer("6759649826438453").unwrap(),
+            CardIssuer::Maestro
+        ));
+    }
+
+    #[test]
+    fn test_get_card_network_from_card_number() {
+        let card = domain::Card {
+            card_number: cards::CardNumber::from_str("5555555555554444").unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(
+            card.get_card_network().unwrap(),
+            api_models::enums::CardNetwork::Mastercard
+        );
+    }
+
+    #[test]
+    fn test_get_card_isin_and_extended_bin() {
+        let card = domain::Card {
+            card_number: cards::CardNumber::from_str("4111 1111 1111 1111").unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(card.get_card_isin().expose(), "411111");
+        assert_eq!(card.get_extended_bin().expose(), "41111111");
+
+        let amex_card = domain::Card {
+            card_number: cards::CardNumber::from_str("378282246310005").unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(amex_card.get_card_isin().expose(), "378282");
+        assert_eq!(amex_card.get_extended_bin().expose(), "37828224");
+    }
+
+    #[test]
+    fn test_is_test_card() {
+        let test_card = domain::Card {
+            card_number: cards::CardNumber::from_str("4242 4242 4242 4242").unwrap(),
+            ..Default::default()
+        };
+        assert!(test_card.is_test_card());
+
+        let live_card = domain::Card {
+            card_number: cards::CardNumber::from_str("4539148803436467").unwrap(),
+            ..Default::default()
+        };
+        assert!(!live_card.is_test_card());
+    }
+
+    #[test]
+    fn test_get_card_holder_name_from_card() {
+        let card = domain::Card {
+            card_holder_name: Some(Secret::new("John Doe".to_string())),
+            ..Default::default()
+        };
+        assert_eq!(card.get_card_holder_name().unwrap().expose(), "John Doe");
+
+        let router_data = get_router_data_with_address(
+            (),
+            get_billing_address_with_name(Some("Jane"), Some("Smith")),
+        );
+        assert_eq!(
+            card.get_card_holder_name_or_billing_name(&router_data)
+                .unwrap()
+                .expose(),
+            "John Doe"
+        );
+    }
+
+    #[test]
+    fn test_get_card_holder_name_falls_back_to_billing_name() {
+        let card = domain::Card::default();
+        assert!(card.get_card_holder_name().is_err());
+
+        let router_data = get_router_data_with_address(
+            (),
+            get_billing_address_with_name(Some("Jane"), Some("Smith")),
+        );
+        assert_eq!(
+            card.get_card_holder_name_or_billing_name(&router_data)
+                .unwrap()
+                .expose(),
+            "Jane Smith"
+        );
+
+        let router_data = get_router_data_with_address((), types::PaymentAddress::default());
+        assert!(card
+            .get_card_holder_name_or_billing_name(&router_data)
+            .is_err());
+    }
+
+    #[test]
+    fn test_get_normalized_zip_for_uk_postcode() {
+        let address = api::AddressDetails {
+            zip: Some(Secret::new(" sw1a 1aa ".to_string())),
+            ..Default::default()
+        };
+        assert_eq!(address.get_normalized_zip().unwrap().expose(), "SW1A1AA");
+    }
+
+    #[test]
+    fn test_get_normalized_zip_for_us_zip_plus_four() {
+        let address = api::AddressDetails {
+            zip: Some(Secret::new("94107-1234".to_string())),
+            ..Default::default()
+        };
+        assert_eq!(address.get_normalized_zip().unwrap().expose(), "94107-1234");
+
+        assert!(api::AddressDetails::default().get_normalized_zip().is_err());
+    }
+
+    #[test]
+    fn test_dedupe_address_lines_with_duplicates() {
+        let mut address = api::AddressDetails {
+            line1: Some(Secret::new("221B Baker Street".to_string())),
+            line2: Some(Secret::new("221B Baker Street ".to_string())),
+            line3: Some(Secret::new("221B Baker Street".to_string())),
+            ..Default::default()
+        };
+        dedupe_address_lines(&mut address);
+        assert_eq!(
+            address.line1.map(|line1| line1.expose()),
+            Some("221B Baker Street".to_string())
+        );
+        assert!(address.line2.is_none());
+        assert!(address.line3.is_none());
+    }
+
+    #[test]
+    fn test_dedupe_address_lines_with_distinct_lines() {
+        let address = api::AddressDetails {
+            line1: Some(Secret::new("221B Baker Street".to_string())),
+            line2: Some(Secret::new("Marylebone".to_string())),
+            line3: Some(Secret::new("London".to_string())),
+            ..Default::default()
+        };
+        let mut deduped_address = address.clone();
+        dedupe_address_lines(&mut deduped_address);
+        assert_eq!(deduped_address, address);
+    }
+
+    #[test]
+    fn test_convert_to_settlement_currency() {
+        assert_eq!(
+            convert_to_settlement_currency(
+                MinorUnit::new(10000),
+                enums::Currency::USD,
+                enums::Currency::EUR,
+                0.92
+            )
+            .unwrap(),
+            MinorUnit::new(9200)
+        );
+        assert!(convert_to_settlement_currency(
+            MinorUnit::new(10000),
+            enums::Currency::USD,
+            enums::Currency::EUR,
+            0.0
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_convert_to_zero_decimal_settlement_currency() {
+        assert_eq!(
+            convert_to_settlement_currency(
+                MinorUnit::new(1050),
+                enums::Currency::USD,
+                enums::Currency::JPY,
+                151.37
+            )
+            .unwrap(),
+            MinorUnit::new(1589)
+        );
+    }
+
+    #[test]
+    fn test_invalid_country_codes_are_rejected_on_deserialization() {
+        assert!(serde_json::from_value::<api::AddressDetails>(
+            serde_json::json!({ "country": "UK" })
+        )
+        .is_err());
+        assert!(serde_json::from_value::<api::AddressDetails>(
+            serde_json::json!({ "country": "" })
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_redact_url_query() {
+        assert_eq!(
+            redact_url_query(
+                "https://example.com/payments/redirect?token=secret_123&status=succeeded",
+                &["token"]
+            ),
+            "https://example.com/payments/redirect?token=*****&status=succeeded"
+        );
+        assert_eq!(
+            redact_url_query(
+                "https://example.com/webhooks?status=succeeded#section",
+                &["token"]
+            ),
+            "https://example.com/webhooks?status=succeeded#section"
+        );
+        assert_eq!(
+            redact_url_query("https://example.com/webhooks", &["token"]),
+            "https://example.com/webhooks"
+        );
+    }
+
+    #[test]
+    fn test_get_billing_full_name_with_first_and_last_name() {
+        let router_data = get_router_data_with_address(
+            (),
+            get_billing_address_with_name(Some("John"), Some("Doe")),
+        );
+        assert_eq!(
+            router_data.get_billing_full_name().unwrap().expose(),
+            "John Doe"
+        );
+        assert_eq!(
+            router_data
+                .get_optional_billing_full_name()
+                .map(|full_name| full_name.expose()),
+            Some("John Doe".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_billing_full_name_with_first_name_only() {
+        let router_data = get_router_data_with_address(
+            (),
+            get_billing_address_with_name(Some(" John "), Some("")),
+        );
+        assert_eq!(
+            router_data.get_billing_full_name().unwrap().expose(),
+            "John"
+        );
+    }
+
+    #[test]
+    fn test_get_billing_full_name_without_names() {
+        let router_data =
+            get_router_data_with_address((), get_billing_address_with_name(None, None));
+        assert!(router_data.get_billing_full_name().is_err());
+        assert!(router_data.get_optional_billing_full_name().is_none());
+    }
+
+    #[test]
+    fn test_authorize_request_minor_amount() {
+        let request = types::PaymentsAuthorizeData {
+            payment_method_data: domain::PaymentMethodData::MandatePayment,
+            amount: 1000,
+            minor_amount: MinorUnit::new(1000),
+            email: None,
+            customer_name: None,
+            currency: enums::Currency::USD,
+            confirm: true,
+            statement_descriptor_suffix: None,
+            statement_descriptor: None,
+            capture_method: None,
+            router_return_url: None,
+            webhook_url: None,
+            complete_authorize_url: None,
+            setup_future_usage: None,
+            mandate_id: None,
+            off_session: None,
+            customer_acceptance: None,
+            setup_mandate_details: None,
+            browser_info: None,
+            order_details: None,
+            order_category: None,
+            session_token: None,
+            enrolled_for_3ds: false,
+            related_transaction_id: None,
+            payment_experience: None,
+            payment_method_type: None,
+            surcharge_details: None,
+            customer_id: None,
+            request_incremental_authorization: false,
+            metadata: None,
+            authentication_data: None,
+            charges: None,
+            merchant_order_reference_id: None,
+            integrity_object: None,
+        };
+        assert_eq!(request.get_minor_amount().unwrap(), MinorUnit::new(1000));
+    }
+
+    #[test]
+    fn test_capture_request_minor_amount() {
+        let request = types::PaymentsCaptureData {
+            minor_payment_amount: MinorUnit::new(1000),
+            minor_amount_to_capture: MinorUnit::new(600),
+            ..Default::default()
+        };
+        assert_eq!(request.get_minor_amount().unwrap(), MinorUnit::new(600));
+    }
+
+    #[test]
+    fn test_cancel_request_minor_amount() {
+        let request = types::PaymentsCancelData {
+            amount: Some(1000),
+            minor_amount: Some(MinorUnit::new(1000)),
+            ..Default::default()
+        };
+        assert_eq!(request.get_minor_amount().unwrap(), MinorUnit::new(1000));
+
+        let request = types::PaymentsCancelData {
+            amount: Some(500),
+            ..Default::default()
+        };
+        assert_eq!(request.get_minor_amount().unwrap(), MinorUnit::new(500));
+
+        let request = types::PaymentsCancelData::default();
+        assert!(request.get_minor_amount().is_err());
+    }
+
+    #[test]
+    fn test_setup_mandate_request_minor_amount() {
+        let request = types::SetupMandateRequestData {
+            currency: enums::Currency::USD,
+            payment_method_data: domain::PaymentMethodData::MandatePayment,
+            amount: Some(0),
+            minor_amount: Some(MinorUnit::new(0)),
+            confirm: true,
+            statement_descriptor_suffix: None,
+            customer_acceptance: None,
+            mandate_id: None,
+            setup_future_usage: None,
+            off_session: None,
+            setup_mandate_details: None,
+            router_return_url: None,
+            browser_info: None,
+            email: None,
+            customer_name: None,
+            return_url: None,
+            payment_method_type: None,
+            request_incremental_authorization: false,
+            metadata: None,
+        };
+        assert_eq!(request.get_minor_amount().unwrap(), MinorUnit::new(0));
+
+        let request = types::SetupMandateRequestData {
+            amount: Some(100),
+            minor_amount: None,
+            ..request
+        };
+        assert_eq!(request.get_minor_amount().unwrap(), MinorUnit::new(100));
+
+        let request = types::SetupMandateRequestData {
+            amount: None,
+            minor_amount: None,
+            ..request
+        };
+        assert!(request.get_minor_amount().is_err());
+    }
+
+    #[test]
+    fn test_get_optional_language() {
+        let mut router_data = get_router_data_with_address(
+            types::PaymentsCaptureData::default(),
+            types::PaymentAddress::default(),
+        );
+        assert_eq!(router_data.get_optional_language(), None);
+
+        router_data.header_payload = Some(api_models::payments::HeaderPayload {
+            locale: Some("zh_hant_tw,en;q=0.8".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(
+            router_data.get_optional_language(),
+            Some("zh-Hant-TW".to_string())
+        );
+
+        router_data.request.browser_info = Some(BrowserInformation {
+            language: Some("en-us".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(
+            router_data.get_optional_language(),
+            Some("en-US".to_string())
+        );
+
+        router_data.request.browser_info = Some(BrowserInformation {
+            language: Some("*".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(
+            router_data.get_optional_language(),
+            Some("zh-Hant-TW".to_string())
+        );
+    }
+
+    #[test]
+    fn test_browser_info_required_accessors() {
+        let browser_info = BrowserInformation {
+            accept_header: Some("text/html".to_string()),
+            user_agent: Some("Mozilla/5.0".to_string()),
+            java_enabled: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(browser_info.get_accept_header().unwrap(), "text/html");
+        assert_eq!(browser_info.get_user_agent().unwrap(), "Mozilla/5.0");
+        assert!(!browser_info.get_java_enabled().unwrap());
+
+        let error = browser_info.get_ip_address().unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "browser_info.ip_address"
+            }
+        ));
+    }
+
+    #[test]
+    fn test_get_optional_connector_refund_id() {
+        let request = types::RefundsData {
+            refund_id: "ref_123".to_string(),
+            connector_transaction_id: "txn_123".to_string(),
+            connector_refund_id: None,
+            currency: enums::Currency::USD,
+            payment_amount: 1000,
+            reason: None,
+            webhook_url: None,
+            refund_amount: 500,
+            connector_metadata: None,
+            browser_info: None,
+            charges: None,
+            minor_payment_amount: MinorUnit::new(1000),
+            minor_refund_amount: MinorUnit::new(500),
+            integrity_object: None,
+        };
+        assert_eq!(request.get_optional_connector_refund_id(), None);
+        assert!(request.get_connector_refund_id().is_err());
+
+        let request = types::RefundsData {
+            connector_refund_id: Some("re_123".to_string()),
+            ..request
+        };
+        assert_eq!(
+            request.get_optional_connector_refund_id(),
+            Some("re_123".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_card_issuer_with_unicode_separators() {
+        assert_eq!(
+            get_card_issuer("4111\u{00A0}1111\u{00A0}1111\u{00A0}1111").unwrap(),
+            CardIssuer::Visa
+        );
+        assert_eq!(
+            get_card_issuer("5555-5555-5555-4444").unwrap(),
+            CardIssuer::Master
+        );
+        assert_eq!(
+            get_card_issuer("3782\u{2009}822463\u{2011}10005").unwrap(),
+            CardIssuer::AmericanExpress
+        );
+    }
+
+    #[test]
+    fn test_get_ip_address_as_ip() {
+        let browser_info: BrowserInformation =
+            serde_json::from_value(serde_json::json!({ "ip_address": "192.168.1.1" })).unwrap();
+        assert_eq!(
+            browser_info.get_ip_address_as_ip().unwrap(),
+            std::net::IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 1, 1))
+        );
+
+        let browser_info: BrowserInformation =
+            serde_json::from_value(serde_json::json!({ "ip_address": "2001:db8::1" })).unwrap();
+        assert!(browser_info.get_ip_address_as_ip().unwrap().is_ipv6());
+
+        // Malformed addresses are rejected when the browser info is deserialized
+        assert!(serde_json::from_value::<BrowserInformation>(
+            serde_json::json!({ "ip_address": "not-an-ip" })
+        )
+        .is_err());
+
+        let error = BrowserInformation::default()
+            .get_ip_address_as_ip()
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "browser_info.ip_address"
+            }
+        ));
+    }
+}
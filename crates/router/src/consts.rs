@@ -108,6 +108,12 @@ pub const MAX_INTENT_FULFILLMENT_EXPIRY: u32 = 1800;
 /// Min payment intent fulfillment expiry
 pub const MIN_INTENT_FULFILLMENT_EXPIRY: u32 = 60;
 
+/// Max combined length of the statement descriptor and its suffix enforced by card networks
+pub const MAX_STATEMENT_DESCRIPTOR_LENGTH: usize = 22;
+
+/// Documented max length of the connector response reference id, longer ids are logged
+pub const MAX_CONNECTOR_RESPONSE_REFERENCE_ID_LENGTH: usize = 255;
+
 pub const LOCKER_HEALTH_CALL_PATH: &str = "/health";
 
 pub const AUTHENTICATION_ID_PREFIX: &str = "authn";
@@ -133,6 +139,24 @@ pub const MAX_ALLOWED_AMOUNT: i64 = 999999999;
 pub const DEFAULT_UNIFIED_ERROR_CODE: &str = "UE_000";
 pub const DEFAULT_UNIFIED_ERROR_MESSAGE: &str = "Something went wrong";
 
+/// Response headers in which connectors commonly return their own request or trace identifier
+pub const CONNECTOR_REQUEST_ID_HEADERS: [&str; 3] =
+    ["x-request-id", "request-id", "x-correlation-id"];
+
+/// Test card numbers documented by connectors for their sandbox environments
+pub const TEST_CARD_NUMBERS: [&str; 10] = [
+    "4242424242424242",
+    "4111111111111111",
+    "4000056655665556",
+    "5555555555554444",
+    "5200828282828210",
+    "2223003122003222",
+    "378282246310005",
+    "6011111111111117",
+    "3056930009020004",
+    "3566002020360505",
+];
+
 // Recon's feature tag
 pub const RECON_FEATURE_TAG: &str = "RECONCILIATION AND SETTLEMENT";
 
@@ -151,3 +175,18 @@ pub const VAULT_RETRIEVE_REQUEST_URL: &str = "/vault/retrieve";
 /// Vault Header content type
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 pub const VAULT_HEADER_CONTENT_TYPE: &str = "application/json";
+
+/// Maximum size of the serialized customer metadata, in bytes
+pub const CUSTOMER_METADATA_MAX_SIZE_IN_BYTES: usize = 16 * 1024;
+
+/// Query parameters whose values are masked when connector urls are logged
+pub const SENSITIVE_URL_QUERY_PARAMS: &[&str] = &[
+    "token",
+    "access_token",
+    "api_key",
+    "apikey",
+    "key",
+    "secret",
+    "signature",
+    "password",
+];
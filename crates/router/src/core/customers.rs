@@ -8,6 +8,7 @@ use common_utils::{
         keymanager::{Identifier, KeyManagerState, ToEncryptable},
         Description,
     },
+    validation,
 };
 use error_stack::{report, ResultExt};
 use masking::{Secret, SwitchStrategy};
@@ -936,6 +937,8 @@ pub async fn update_customer(
     key_store: domain::MerchantKeyStore,
     id: customers::UpdateCustomerId,
 ) -> errors::CustomerResponse<customers::CustomerResponse> {
+    validate_customer_update_request(&update_customer)?;
+
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
     //Add this in update call if customer can be updated anywhere else
@@ -968,6 +971,26 @@ pub async fn update_customer(
     update_customer.generate_response(&updated_customer)
 }
 
+fn validate_customer_update_request(
+    update_customer: &customers::CustomerUpdateRequest,
+) -> errors::CustomResult<(), errors::CustomersErrorResponse> {
+    update_customer
+        .metadata
+        .as_ref()
+        .map(|metadata| {
+            validation::validate_metadata_size(
+                metadata,
+                crate::consts::CUSTOMER_METADATA_MAX_SIZE_IN_BYTES,
+            )
+        })
+        .transpose()
+        .map_err(|err| {
+            let message = err.current_context().to_string();
+            err.change_context(errors::CustomersErrorResponse::InvalidRequestData { message })
+        })?;
+    Ok(())
+}
+
 #[async_trait::async_trait]
 trait CustomerUpdateBridge {
     async fn create_domain_model_from_request<'a>(
@@ -1188,22 +1211,24 @@ impl CustomerUpdateBridge for customers::CustomerUpdateRequest {
         let encryptable_customer = CustomerRequestWithEmail::from_encryptable(encrypted_data)
             .change_context(errors::CustomersErrorResponse::InternalServerError)?;
 
+        let customer_update = storage::CustomerUpdate::Update {
+            name: encryptable_customer.name,
+            email: encryptable_customer.email,
+            phone: Box::new(encryptable_customer.phone),
+            phone_country_code: self.phone_country_code.clone(),
+            metadata: self.metadata.clone(),
+            description: self.description.clone(),
+            connector_customer: None,
+            address_id: address.clone().map(|addr| addr.address_id),
+        };
+
         let response = db
             .update_customer_by_customer_id_merchant_id(
                 key_manager_state,
                 domain_customer.customer_id.to_owned(),
                 merchant_account.get_id().to_owned(),
                 domain_customer.to_owned(),
-                storage::CustomerUpdate::Update {
-                    name: encryptable_customer.name,
-                    email: encryptable_customer.email,
-                    phone: Box::new(encryptable_customer.phone),
-                    phone_country_code: self.phone_country_code.clone(),
-                    metadata: self.metadata.clone(),
-                    description: self.description.clone(),
-                    connector_customer: None,
-                    address_id: address.clone().map(|addr| addr.address_id),
-                },
+                customer_update,
                 key_store,
                 merchant_account.storage_scheme,
             )
@@ -1278,25 +1303,27 @@ impl CustomerUpdateBridge for customers::CustomerUpdateRequest {
         let encryptable_customer = CustomerRequestWithEmail::from_encryptable(encrypted_data)
             .change_context(errors::CustomersErrorResponse::InternalServerError)?;
 
+        let customer_update = storage::CustomerUpdate::Update {
+            name: encryptable_customer.name,
+            email: Box::new(encryptable_customer.email),
+            phone: Box::new(encryptable_customer.phone),
+            phone_country_code: self.phone_country_code.clone(),
+            metadata: self.metadata.clone(),
+            description: self.description.clone(),
+            connector_customer: None,
+            default_billing_address: encrypted_customer_billing_address.map(Into::into),
+            default_shipping_address: encrypted_customer_shipping_address.map(Into::into),
+            default_payment_method_id: Some(self.default_payment_method_id.clone()),
+            status: None,
+        };
+
         let response = db
             .update_customer_by_global_id(
                 key_manager_state,
                 domain_customer.id.to_owned(),
                 domain_customer.to_owned(),
                 merchant_account.get_id(),
-                storage::CustomerUpdate::Update {
-                    name: encryptable_customer.name,
-                    email: Box::new(encryptable_customer.email),
-                    phone: Box::new(encryptable_customer.phone),
-                    phone_country_code: self.phone_country_code.clone(),
-                    metadata: self.metadata.clone(),
-                    description: self.description.clone(),
-                    connector_customer: None,
-                    default_billing_address: encrypted_customer_billing_address.map(Into::into),
-                    default_shipping_address: encrypted_customer_shipping_address.map(Into::into),
-                    default_payment_method_id: Some(self.default_payment_method_id.clone()),
-                    status: None,
-                },
+                customer_update,
                 key_store,
                 merchant_account.storage_scheme,
             )
@@ -16,6 +16,9 @@ pub enum CustomersErrorResponse {
 
     #[error("Customer with the given customer id already exists")]
     CustomerAlreadyExists,
+
+    #[error("{message}")]
+    InvalidRequestData { message: String },
 }
 
 impl actix_web::ResponseError for CustomersErrorResponse {
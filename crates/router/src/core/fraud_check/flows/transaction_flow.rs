@@ -128,6 +128,7 @@ impl
             payment_method_balance: None,
             connector_http_status_code: None,
             external_latency: None,
+            connector_request_id: None,
             connector_api_version: None,
             payment_method_status: None,
             apple_pay_flow: None,
@@ -302,10 +302,10 @@ where
         ) {
             *frm_suggestion = Some(FrmSuggestion::FrmManualReview);
         } else if matches!(frm_data.fraud_check.frm_status, FraudCheckStatus::Legit)
-            && matches!(
-                frm_data.fraud_check.payment_capture_method,
-                Some(CaptureMethod::Automatic)
-            )
+            && frm_data
+                .fraud_check
+                .payment_capture_method
+                .map_or(false, CaptureMethod::is_automatic)
         {
             let capture_request = api_models::payments::PaymentsCaptureRequest {
                 payment_id: frm_data.payment_intent.get_id().to_owned(),
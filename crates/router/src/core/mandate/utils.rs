@@ -68,6 +68,7 @@ pub async fn construct_mandate_revoke_router_data(
         test_mode: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         #[cfg(feature = "payouts")]
@@ -281,6 +281,7 @@ where
                 payment_data.get_payment_attempt().capture_method,
             ) {
                 (false, Some(storage_enums::CaptureMethod::Automatic))
+                | (false, Some(storage_enums::CaptureMethod::SequentialAutomatic))
                 | (false, Some(storage_enums::CaptureMethod::Scheduled)) => {
                     if let Some(info) = &mut frm_info {
                         if let Some(frm_data) = &mut info.frm_data {
@@ -949,6 +950,8 @@ where
         &state.base_url,
         operation,
         &state.conf.connector_request_reference_id_config,
+        &state.conf.manual_retry_config,
+        &state.conf.latency_header_config,
         connector_http_status_code,
         external_latency,
         header_payload.x_hs_latency,
@@ -1172,6 +1175,7 @@ impl PaymentRedirectFlow for PaymentRedirectCompleteAuthorize {
                         api_models::payments::NextActionData::WaitScreenInformation{..} => None,
                         api_models::payments::NextActionData::ThreeDsInvoke{..} => None,
                         api_models::payments::NextActionData::InvokeSdkClient{..} => None,
+                        api_models::payments::NextActionData::OpenBankingConsent{..} => None,
                     })
                     .ok_or(errors::ApiErrorResponse::InternalServerError)
 
@@ -1604,6 +1608,11 @@ where
     {
         payment_data.set_merchant_connector_id_in_attempt(merchant_connector_account.get_mca_id());
     }
+    payment_data.set_merchant_connector_account_label(
+        merchant_connector_account
+            .get_mca_id()
+            .zip(merchant_connector_account.get_connector_label()),
+    );
 
     operation
         .to_domain()?
@@ -2363,22 +2372,38 @@ where
         })) => {
             if connector.connector_name == router_types::Connector::Plaid {
                 router_data = router_data.postprocessing_steps(state, connector).await?;
-                let token = if let Ok(ref res) = router_data.response {
+                let (token, connector_metadata) = if let Ok(ref res) = router_data.response {
                     match res {
                         router_types::PaymentsResponseData::PostProcessingResponse {
                             session_token,
-                        } => session_token
-                            .as_ref()
-                            .map(|token| api::SessionToken::OpenBanking(token.clone())),
-                        _ => None,
+                            connector_metadata,
+                        } => (
+                            session_token
+                                .as_ref()
+                                .map(|token| api::SessionToken::OpenBanking(token.clone())),
+                            connector_metadata.clone(),
+                        ),
+                        _ => (None, None),
                     }
                 } else {
-                    None
+                    (None, None)
                 };
                 if let Some(t) = token {
                     payment_data.push_sessions_token(t);
                 }
 
+                // The consent details are stored on the attempt to be shown as the next action
+                #[cfg(feature = "v1")]
+                if let Some(connector_metadata) = connector_metadata {
+                    update_connector_metadata_in_payment_attempt(
+                        state,
+                        merchant_account,
+                        payment_data,
+                        connector_metadata,
+                    )
+                    .await?;
+                }
+
                 Ok(router_data)
             } else {
                 Ok(router_data)
@@ -2388,6 +2413,41 @@ where
     }
 }
 
+#[cfg(feature = "v1")]
+async fn update_connector_metadata_in_payment_attempt<F, D>(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &mut D,
+    connector_metadata: serde_json::Value,
+) -> RouterResult<()>
+where
+    F: Clone,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send + Sync + Clone,
+{
+    let payment_attempt = payment_data.get_payment_attempt().clone();
+    let payment_attempt_update = storage::PaymentAttemptUpdate::PreprocessingUpdate {
+        status: payment_attempt.status,
+        payment_method_id: payment_attempt.payment_method_id.clone(),
+        connector_metadata: Some(connector_metadata),
+        preprocessing_step_id: payment_attempt.preprocessing_step_id.clone(),
+        connector_transaction_id: payment_attempt.connector_transaction_id.clone(),
+        connector_response_reference_id: payment_attempt.connector_response_reference_id.clone(),
+        updated_by: merchant_account.storage_scheme.to_string(),
+    };
+    let payment_attempt = state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_attempt,
+            payment_attempt_update,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable("Error while updating the payment_attempt")?;
+    payment_data.set_payment_attempt(payment_attempt);
+    Ok(())
+}
+
 pub fn is_preprocessing_required_for_wallets(connector_name: String) -> bool {
     connector_name == *"trustpay" || connector_name == *"payme"
 }
@@ -2897,6 +2957,8 @@ where
     pub recurring_details: Option<RecurringDetails>,
     pub poll_config: Option<router_types::PollConfig>,
     pub tax_data: Option<TaxData>,
+    /// Label of the merchant connector account along with the id of the account it belongs to
+    pub merchant_connector_account_label: Option<(id_type::MerchantConnectorAccountId, String)>,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -3070,19 +3132,30 @@ pub async fn list_payments(
     key_store: domain::MerchantKeyStore,
     constraints: api::PaymentListConstraints,
 ) -> RouterResponse<api::PaymentListResponse> {
-    use hyperswitch_domain_models::errors::StorageError;
+    use hyperswitch_domain_models::{
+        errors::StorageError, payments::payment_intent::PaymentIntentFetchConstraints,
+    };
     helpers::validate_payment_list_request(&constraints)?;
+    let limit = constraints.limit;
     let merchant_id = merchant.get_id();
     let db = state.store.as_ref();
+    let mut fetch_constraints: PaymentIntentFetchConstraints =
+        (constraints, profile_id_list).try_into()?;
+    // One intent more than the limit is fetched to know whether there is a next page
+    if let PaymentIntentFetchConstraints::List(list_params) = &mut fetch_constraints {
+        list_params.limit = Some(limit.saturating_add(1));
+    }
     let payment_intents = helpers::filter_by_constraints(
         &state,
-        &(constraints, profile_id_list).try_into()?,
+        &fetch_constraints,
         merchant_id,
         &key_store,
         merchant.storage_scheme,
     )
     .await
     .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let (payment_intents, next_cursor) =
+        transformers::get_payment_list_page(payment_intents, limit);
 
     let collected_futures = payment_intents.into_iter().map(|pi| {
         async {
@@ -3122,17 +3195,11 @@ pub async fn list_payments(
     //Will collect responses in same order async, leading to sorted responses
 
     //Converting Intent-Attempt array to Response if no error
-    let data: Vec<api::PaymentsResponse> = pi_pa_tuple_vec
-        .change_context(errors::ApiErrorResponse::InternalServerError)?
-        .into_iter()
-        .map(ForeignFrom::foreign_from)
-        .collect();
+    let pi_pa_tuple_vec =
+        pi_pa_tuple_vec.change_context(errors::ApiErrorResponse::InternalServerError)?;
 
     Ok(services::ApplicationResponse::Json(
-        api::PaymentListResponse {
-            size: data.len(),
-            data,
-        },
+        api::PaymentListResponse::foreign_from((pi_pa_tuple_vec, next_cursor)),
     ))
 }
 
@@ -4500,6 +4567,7 @@ where
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
+    let payment_method_data = transaction_data.payment_method_data;
     let connectors = routing::perform_eligibility_analysis_with_fallback(
         &state.clone(),
         key_store,
@@ -4512,6 +4580,16 @@ where
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
 
+    let connectors = routing::prefer_sandbox_connectors_for_test_card(
+        state,
+        key_store,
+        connectors,
+        payment_method_data,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed to prefer sandbox connectors for test card")?;
+
     let connector_data = connectors
         .into_iter()
         .map(|conn| {
@@ -5035,6 +5113,11 @@ pub trait OperationSessionGetters<F> {
     fn get_token_data(&self) -> Option<&storage::PaymentTokenData>;
     fn get_mandate_connector(&self) -> Option<&MandateConnectorDetails>;
     fn get_force_sync(&self) -> Option<bool>;
+    fn get_connector_customer_id(&self) -> Option<&str>;
+    fn get_merchant_connector_account_label(
+        &self,
+    ) -> Option<(&id_type::MerchantConnectorAccountId, &str)>;
+    fn get_surcharge_amount(&self) -> Option<MinorUnit>;
 }
 
 pub trait OperationSessionSetters<F> {
@@ -5074,6 +5157,10 @@ pub trait OperationSessionSetters<F> {
         straight_through_algorithm: serde_json::Value,
     );
     fn set_connector_in_payment_attempt(&mut self, connector: Option<String>);
+    fn set_merchant_connector_account_label(
+        &mut self,
+        merchant_connector_account_label: Option<(id_type::MerchantConnectorAccountId, String)>,
+    );
 }
 
 impl<F: Clone> OperationSessionGetters<F> for PaymentData<F> {
@@ -5199,6 +5286,26 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentData<F> {
     fn get_force_sync(&self) -> Option<bool> {
         self.force_sync
     }
+
+    fn get_connector_customer_id(&self) -> Option<&str> {
+        self.connector_customer_id.as_deref()
+    }
+
+    fn get_merchant_connector_account_label(
+        &self,
+    ) -> Option<(&id_type::MerchantConnectorAccountId, &str)> {
+        self.merchant_connector_account_label
+            .as_ref()
+            .map(|(merchant_connector_id, label)| (merchant_connector_id, label.as_str()))
+    }
+
+    fn get_surcharge_amount(&self) -> Option<MinorUnit> {
+        transformers::get_payment_surcharge_details(
+            self.surcharge_details.as_ref(),
+            &self.payment_attempt,
+        )
+        .map(|surcharge_details| surcharge_details.surcharge_amount)
+    }
 }
 
 impl<F: Clone> OperationSessionSetters<F> for PaymentData<F> {
@@ -5294,6 +5401,13 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentData<F> {
     fn set_connector_in_payment_attempt(&mut self, connector: Option<String>) {
         self.payment_attempt.connector = connector;
     }
+
+    fn set_merchant_connector_account_label(
+        &mut self,
+        merchant_connector_account_label: Option<(id_type::MerchantConnectorAccountId, String)>,
+    ) {
+        self.merchant_connector_account_label = merchant_connector_account_label;
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -5414,6 +5528,20 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentIntentData<F> {
     fn get_force_sync(&self) -> Option<bool> {
         todo!()
     }
+
+    fn get_connector_customer_id(&self) -> Option<&str> {
+        todo!()
+    }
+
+    fn get_merchant_connector_account_label(
+        &self,
+    ) -> Option<(&id_type::MerchantConnectorAccountId, &str)> {
+        todo!()
+    }
+
+    fn get_surcharge_amount(&self) -> Option<MinorUnit> {
+        todo!()
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -5510,4 +5638,11 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentIntentData<F> {
     fn set_connector_in_payment_attempt(&mut self, _connector: Option<String>) {
         todo!()
     }
+
+    fn set_merchant_connector_account_label(
+        &mut self,
+        _merchant_connector_account_label: Option<(id_type::MerchantConnectorAccountId, String)>,
+    ) {
+        todo!()
+    }
 }
@@ -898,7 +898,7 @@ pub fn validate_amount_to_capture_and_capture_method(
         .or(payment_attempt
             .map(|payment_attempt| payment_attempt.capture_method.unwrap_or_default()))
         .unwrap_or_default();
-    if capture_method == api_enums::CaptureMethod::Automatic {
+    if capture_method.is_automatic() {
         let original_amount = request
             .amount
             .map(MinorUnit::from)
@@ -1099,6 +1099,21 @@ fn validate_new_mandate_request(
         .clone()
         .get_required_value("mandate_data")?;
 
+    // The initial customer initiated mandate setup cannot be confirmed without the customer acceptance
+    fp_utils::when(
+        is_confirm_operation
+            && req.setup_future_usage == Some(api_enums::FutureUsage::OffSession)
+            && req.customer_acceptance.is_none()
+            && mandate_data.customer_acceptance.is_none(),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "`customer_acceptance` is required when confirming a mandate setup \
+                          with `setup_future_usage` as `off_session`"
+                    .into()
+            }))
+        },
+    )?;
+
     // Only use this validation if the customer_acceptance is present
     if mandate_data
         .customer_acceptance
@@ -1209,6 +1224,42 @@ pub fn create_webhook_url(
         connector_name
     )
 }
+
+/// Order tax of the payment. The tax stored on the attempt takes precedence, followed by the tax
+/// calculated on the intent for the payment method type and then the default tax of the intent.
+pub fn get_order_tax_amount(
+    attempt_order_tax_amount: Option<MinorUnit>,
+    tax_details: Option<&diesel_models::TaxDetails>,
+) -> Option<MinorUnit> {
+    attempt_order_tax_amount.or_else(|| {
+        tax_details.and_then(|tax| {
+            tax.payment_method_type
+                .as_ref()
+                .map(|tax| tax.order_tax_amount)
+                .or_else(|| tax.default.as_ref().map(|tax| tax.order_tax_amount))
+        })
+    })
+}
+
+/// Webhook url scoped to the merchant connector account, so that multiple accounts of the same
+/// connector can be told apart. Falls back to the connector name when the account id is absent.
+pub fn get_webhook_url_with_merchant_connector_id(
+    router_base_url: &String,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+    connector_name: &String,
+) -> String {
+    match merchant_connector_id {
+        Some(merchant_connector_id) => format!(
+            "{}/webhooks/{}/{}",
+            router_base_url,
+            merchant_id.get_string_repr(),
+            merchant_connector_id.get_string_repr()
+        ),
+        None => create_webhook_url(router_base_url, merchant_id, connector_name),
+    }
+}
+
 pub fn create_complete_authorize_url(
     router_base_url: &String,
     payment_attempt: &PaymentAttempt,
@@ -2354,17 +2405,14 @@ pub fn should_store_payment_method_data_in_vault(
 pub(crate) fn validate_capture_method(
     capture_method: storage_enums::CaptureMethod,
 ) -> RouterResult<()> {
-    utils::when(
-        capture_method == storage_enums::CaptureMethod::Automatic,
-        || {
-            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
-                field_name: "capture_method".to_string(),
-                current_flow: "captured".to_string(),
-                current_value: capture_method.to_string(),
-                states: "manual, manual_multiple, scheduled".to_string()
-            }))
-        },
-    )
+    utils::when(capture_method.is_automatic(), || {
+        Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+            field_name: "capture_method".to_string(),
+            current_flow: "captured".to_string(),
+            current_value: capture_method.to_string(),
+            states: "manual, manual_multiple, scheduled".to_string()
+        }))
+    })
 }
 
 #[instrument(skip_all)]
@@ -3536,6 +3584,13 @@ impl MerchantConnectorAccountType {
         }
     }
 
+    pub fn get_connector_label(&self) -> Option<String> {
+        match self {
+            Self::DbVal(db_val) => db_val.connector_label.clone(),
+            Self::CacheVal(_) => None,
+        }
+    }
+
     pub fn get_additional_merchant_data(
         &self,
     ) -> Option<Encryptable<masking::Secret<serde_json::Value>>> {
@@ -3738,6 +3793,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         connector_api_version: router_data.connector_api_version,
         connector_http_status_code: router_data.connector_http_status_code,
         external_latency: router_data.external_latency,
+        connector_request_id: router_data.connector_request_id,
         apple_pay_flow: router_data.apple_pay_flow,
         frm_metadata: router_data.frm_metadata,
         refund_id: router_data.refund_id,
@@ -3962,6 +4018,8 @@ impl AttemptType {
             profile_id: old_payment_attempt.profile_id,
             shipping_cost: old_payment_attempt.shipping_cost,
             order_tax_amount: None,
+            connector_request_id: None,
+            decline_category: None,
         }
     }
 
@@ -4068,6 +4126,8 @@ pub fn is_manual_retry_allowed(
     attempt_status: &storage_enums::AttemptStatus,
     connector_request_reference_id_config: &ConnectorRequestReferenceIdConfig,
     merchant_id: &id_type::MerchantId,
+    attempt_count: i16,
+    max_attempts: Option<i16>,
 ) -> Option<bool> {
     let is_payment_status_eligible_for_retry = match intent_status {
         enums::IntentStatus::Failed => match attempt_status {
@@ -4116,8 +4176,79 @@ pub fn is_manual_retry_allowed(
     let is_merchant_id_enabled_for_retries = !connector_request_reference_id_config
         .merchant_ids_send_payment_id_as_connector_request_id
         .contains(merchant_id);
-    is_payment_status_eligible_for_retry
-        .map(|payment_status_check| payment_status_check && is_merchant_id_enabled_for_retries)
+    let is_retry_budget_available =
+        max_attempts.map_or(true, |max_attempts| attempt_count < max_attempts);
+    is_payment_status_eligible_for_retry.map(|payment_status_check| {
+        payment_status_check && is_merchant_id_enabled_for_retries && is_retry_budget_available
+    })
+}
+
+/// Decides whether the customer should be nudged towards a different payment method, from the
+/// decline category of a failed attempt. Only hard declines, explicitly mapped as such in the
+/// global status mapping, suggest an alternate payment method and retryable technical failures do
+/// not. `None` is returned when the decline category does not tell either way.
+pub fn should_suggest_alternate_payment_method(
+    decline_category: Option<api_models::gsm::GsmDecision>,
+) -> Option<bool> {
+    decline_category.and_then(|decision| match decision {
+        api_models::gsm::GsmDecision::HardDecline => Some(true),
+        api_models::gsm::GsmDecision::Retry | api_models::gsm::GsmDecision::Requeue => Some(false),
+        api_models::gsm::GsmDecision::DoDefault => None,
+    })
+}
+
+/// Describes how the funds of an attempt were actually captured.
+/// Returns `Automatic` or `Manual` once funds are captured, `NotCaptured` when the attempt
+/// holds or released an authorization without capturing, and `None` while the outcome is unknown.
+pub fn get_capture_executed(
+    attempt_status: &storage_enums::AttemptStatus,
+    capture_method: Option<storage_enums::CaptureMethod>,
+    has_captures: bool,
+) -> Option<storage_enums::CaptureExecuted> {
+    match attempt_status {
+        storage_enums::AttemptStatus::Charged
+        | storage_enums::AttemptStatus::PartialCharged
+        | storage_enums::AttemptStatus::PartialChargedAndChargeable
+        | storage_enums::AttemptStatus::AutoRefunded => {
+            let is_manual_capture = has_captures
+                || matches!(
+                    capture_method,
+                    Some(
+                        storage_enums::CaptureMethod::Manual
+                            | storage_enums::CaptureMethod::ManualMultiple
+                    )
+                );
+            Some(if is_manual_capture {
+                storage_enums::CaptureExecuted::Manual
+            } else {
+                storage_enums::CaptureExecuted::Automatic
+            })
+        }
+
+        storage_enums::AttemptStatus::Authorized
+        | storage_enums::AttemptStatus::CaptureInitiated
+        | storage_enums::AttemptStatus::CaptureFailed
+        | storage_enums::AttemptStatus::Voided
+        | storage_enums::AttemptStatus::VoidInitiated
+        | storage_enums::AttemptStatus::VoidFailed => {
+            Some(storage_enums::CaptureExecuted::NotCaptured)
+        }
+
+        storage_enums::AttemptStatus::Started
+        | storage_enums::AttemptStatus::AuthenticationFailed
+        | storage_enums::AttemptStatus::RouterDeclined
+        | storage_enums::AttemptStatus::AuthenticationPending
+        | storage_enums::AttemptStatus::AuthenticationSuccessful
+        | storage_enums::AttemptStatus::AuthorizationFailed
+        | storage_enums::AttemptStatus::Authorizing
+        | storage_enums::AttemptStatus::CodInitiated
+        | storage_enums::AttemptStatus::Unresolved
+        | storage_enums::AttemptStatus::Pending
+        | storage_enums::AttemptStatus::Failure
+        | storage_enums::AttemptStatus::PaymentMethodAwaited
+        | storage_enums::AttemptStatus::ConfirmationAwaited
+        | storage_enums::AttemptStatus::DeviceDataCollectionPending => None,
+    }
 }
 
 #[cfg(test)]
@@ -4143,6 +4274,239 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    #[test]
+    fn test_suggest_alternate_payment_method() {
+        use api_models::gsm::GsmDecision;
+
+        assert_eq!(
+            super::should_suggest_alternate_payment_method(Some(GsmDecision::HardDecline)),
+            Some(true)
+        );
+        assert_eq!(
+            super::should_suggest_alternate_payment_method(Some(GsmDecision::Retry)),
+            Some(false)
+        );
+        assert_eq!(
+            super::should_suggest_alternate_payment_method(Some(GsmDecision::DoDefault)),
+            None
+        );
+        assert_eq!(super::should_suggest_alternate_payment_method(None), None);
+    }
+
+    #[test]
+    fn test_network_token_payment_method_data_response() {
+        use std::str::FromStr;
+
+        let network_token_data = super::domain::NetworkTokenData {
+            token_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
+            token_exp_month: masking::Secret::new("12".to_string()),
+            token_exp_year: masking::Secret::new("2030".to_string()),
+            token_cryptogram: Some(masking::Secret::new("cryptogram".to_string())),
+            card_issuer: None,
+            card_network: Some(common_enums::CardNetwork::Visa),
+            card_type: Some("credit".to_string()),
+            card_issuing_country: None,
+            bank_code: None,
+            nick_name: None,
+        };
+
+        let payment_method_data_response = api_models::payments::PaymentMethodDataResponse::from(
+            super::get_network_token_additional_payment_data(&network_token_data),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&payment_method_data_response).unwrap(),
+            serde_json::json!({
+                "network_token": {
+                    "last4": "1111",
+                    "card_network": "Visa",
+                    "token_exp_month": "12",
+                    "token_exp_year": "2030",
+                    "card_type": "credit"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_manual_retry_allowed_with_retry_budget() {
+        use super::storage_enums::{AttemptStatus, IntentStatus};
+
+        let connector_request_reference_id_config =
+            super::ConnectorRequestReferenceIdConfig::default();
+        let merchant_id = super::id_type::MerchantId::default();
+        let max_attempts = Some(3);
+        let is_manual_retry_allowed = |attempt_count| {
+            super::is_manual_retry_allowed(
+                &IntentStatus::Failed,
+                &AttemptStatus::Failure,
+                &connector_request_reference_id_config,
+                &merchant_id,
+                attempt_count,
+                max_attempts,
+            )
+        };
+
+        assert_eq!(is_manual_retry_allowed(2), Some(true));
+        assert_eq!(is_manual_retry_allowed(3), Some(false));
+        assert_eq!(is_manual_retry_allowed(4), Some(false));
+        assert_eq!(
+            super::is_manual_retry_allowed(
+                &IntentStatus::Failed,
+                &AttemptStatus::Failure,
+                &connector_request_reference_id_config,
+                &merchant_id,
+                4,
+                None,
+            ),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_capture_executed() {
+        use super::storage_enums::{AttemptStatus, CaptureExecuted, CaptureMethod};
+
+        assert_eq!(
+            super::get_capture_executed(
+                &AttemptStatus::Charged,
+                Some(CaptureMethod::Automatic),
+                false
+            ),
+            Some(CaptureExecuted::Automatic)
+        );
+        assert_eq!(
+            super::get_capture_executed(&AttemptStatus::Charged, Some(CaptureMethod::Manual), true),
+            Some(CaptureExecuted::Manual)
+        );
+        assert_eq!(
+            super::get_capture_executed(
+                &AttemptStatus::Authorized,
+                Some(CaptureMethod::Manual),
+                false
+            ),
+            Some(CaptureExecuted::NotCaptured)
+        );
+        assert_eq!(
+            super::get_capture_executed(&AttemptStatus::Pending, None, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_co_badged_card_networks() {
+        use common_enums::CardNetwork;
+
+        assert_eq!(
+            super::get_co_badged_card_networks(&[CardNetwork::CartesBancaires, CardNetwork::Visa]),
+            Some(vec![CardNetwork::CartesBancaires, CardNetwork::Visa])
+        );
+        assert_eq!(
+            super::get_co_badged_card_networks(&[CardNetwork::Visa, CardNetwork::Visa]),
+            None
+        );
+        assert_eq!(
+            super::get_co_badged_card_networks(&[CardNetwork::Visa]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_validate_payment_charges() {
+        use common_utils::types::MinorUnit;
+
+        let direct_charge = PaymentChargeRequest {
+            charge_type: api_models::enums::PaymentChargeType::Stripe(
+                api_models::enums::StripeChargeType::Direct,
+            ),
+            fees: MinorUnit::new(100),
+            transfer_account_id: "acct_123".to_string(),
+        };
+        assert!(super::validate_payment_charges(&direct_charge).is_ok());
+
+        let destination_charge = PaymentChargeRequest {
+            charge_type: api_models::enums::PaymentChargeType::Stripe(
+                api_models::enums::StripeChargeType::Destination,
+            ),
+            fees: MinorUnit::new(100),
+            transfer_account_id: " ".to_string(),
+        };
+        assert!(matches!(
+            super::validate_payment_charges(&destination_charge),
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "charges.transfer_account_id"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_get_order_tax_amount_on_retried_attempt() {
+        use common_utils::types::MinorUnit;
+
+        let tax_details = diesel_models::TaxDetails {
+            default: Some(diesel_models::DefaultTax {
+                order_tax_amount: MinorUnit::new(50),
+            }),
+            payment_method_type: Some(diesel_models::PaymentMethodTypeTax {
+                order_tax_amount: MinorUnit::new(80),
+                pmt: common_enums::PaymentMethodType::Credit,
+            }),
+        };
+
+        // The first attempt stored the calculated tax, the retried attempt has none of its own
+        let first_attempt_tax =
+            super::get_order_tax_amount(Some(MinorUnit::new(80)), Some(&tax_details));
+        let second_attempt_tax = super::get_order_tax_amount(None, Some(&tax_details));
+        assert_eq!(first_attempt_tax, Some(MinorUnit::new(80)));
+        assert_eq!(second_attempt_tax, first_attempt_tax);
+
+        let default_tax_details = diesel_models::TaxDetails {
+            payment_method_type: None,
+            ..tax_details
+        };
+        assert_eq!(
+            super::get_order_tax_amount(None, Some(&default_tax_details)),
+            Some(MinorUnit::new(50))
+        );
+        assert_eq!(super::get_order_tax_amount(None, None), None);
+    }
+
+    #[test]
+    fn test_get_webhook_url_with_merchant_connector_id() {
+        let router_base_url = "https://sandbox.hyperswitch.io".to_string();
+        let merchant_id = super::id_type::MerchantId::default();
+        let connector_name = "stripe".to_string();
+        let first_mca_id =
+            super::id_type::MerchantConnectorAccountId::wrap("mca_first".to_string()).unwrap();
+        let second_mca_id =
+            super::id_type::MerchantConnectorAccountId::wrap("mca_second".to_string()).unwrap();
+
+        let first_webhook_url = super::get_webhook_url_with_merchant_connector_id(
+            &router_base_url,
+            &merchant_id,
+            Some(&first_mca_id),
+            &connector_name,
+        );
+        let second_webhook_url = super::get_webhook_url_with_merchant_connector_id(
+            &router_base_url,
+            &merchant_id,
+            Some(&second_mca_id),
+            &connector_name,
+        );
+
+        assert_ne!(first_webhook_url, second_webhook_url);
+        assert!(first_webhook_url.ends_with("/mca_first"));
+        assert_eq!(
+            super::get_webhook_url_with_merchant_connector_id(
+                &router_base_url,
+                &merchant_id,
+                None,
+                &connector_name,
+            ),
+            super::create_webhook_url(&router_base_url, &merchant_id, &connector_name)
+        );
+    }
 }
 
 #[instrument(skip_all)]
@@ -4190,6 +4554,7 @@ pub async fn get_additional_payment_data(
                         // These are filled after calling the processor / connector
                         payment_checks: None,
                         authentication_data: None,
+                        co_badged_card_networks: None,
                     },
                 )))
             } else {
@@ -4202,27 +4567,60 @@ pub async fn get_additional_payment_data(
                             .ok()
                     })
                     .await
-                    .flatten()
-                    .map(|card_info| {
-                        api_models::payments::AdditionalPaymentData::Card(Box::new(
-                            api_models::payments::AdditionalCardInfo {
-                                card_issuer: card_info.card_issuer,
-                                card_network: card_info.card_network.clone(),
-                                bank_code: card_info.bank_code,
-                                card_type: card_info.card_type,
-                                card_issuing_country: card_info.card_issuing_country,
-                                last4: last4.clone(),
-                                card_isin: card_isin.clone(),
-                                card_extended_bin: card_extended_bin.clone(),
-                                card_exp_month: Some(card_data.card_exp_month.clone()),
-                                card_exp_year: Some(card_data.card_exp_year.clone()),
-                                card_holder_name: card_data.nick_name.clone(), //todo!
-                                // These are filled after calling the processor / connector
-                                payment_checks: None,
-                                authentication_data: None,
-                            },
-                        ))
-                    });
+                    .flatten();
+                // Co-badged cards carry the secondary network against their extended bin, it is
+                // only looked up for profiles that have enabled the extended card bin
+                let extended_bin_card_network = card_info
+                    .as_ref()
+                    .and(card_extended_bin.as_ref())
+                    .async_and_then(|card_extended_bin| async move {
+                        db.get_card_info(card_extended_bin)
+                            .await
+                            .map_err(|error| services::logger::warn!(card_info_error=?error))
+                            .ok()
+                            .flatten()
+                            .and_then(|extended_bin_card_info| extended_bin_card_info.card_network)
+                    })
+                    .await;
+                let card_info = card_info.map(|card_info| {
+                    let bin_card_networks: Vec<_> = card_info
+                        .card_network
+                        .iter()
+                        .chain(extended_bin_card_network.iter())
+                        .cloned()
+                        .collect();
+                    let co_badged_card_networks = get_co_badged_card_networks(&bin_card_networks);
+                    // For co-badged cards the network requested for the payment is the selected one,
+                    // as long as the bin data lists it
+                    let card_network = co_badged_card_networks
+                        .as_ref()
+                        .and_then(|card_networks| {
+                            card_data
+                                .card_network
+                                .clone()
+                                .filter(|card_network| card_networks.contains(card_network))
+                        })
+                        .or(card_info.card_network.clone());
+                    api_models::payments::AdditionalPaymentData::Card(Box::new(
+                        api_models::payments::AdditionalCardInfo {
+                            card_issuer: card_info.card_issuer,
+                            card_network,
+                            bank_code: card_info.bank_code,
+                            card_type: card_info.card_type,
+                            card_issuing_country: card_info.card_issuing_country,
+                            last4: last4.clone(),
+                            card_isin: card_isin.clone(),
+                            card_extended_bin: card_extended_bin.clone(),
+                            card_exp_month: Some(card_data.card_exp_month.clone()),
+                            card_exp_year: Some(card_data.card_exp_year.clone()),
+                            card_holder_name: card_data.nick_name.clone(), //todo!
+                            // These are filled after calling the processor / connector
+                            payment_checks: None,
+                            authentication_data: None,
+                            co_badged_card_networks,
+                        },
+                    ))
+                });
                 Some(card_info.unwrap_or_else(|| {
                     api_models::payments::AdditionalPaymentData::Card(Box::new(
                         api_models::payments::AdditionalCardInfo {
@@ -4240,6 +4638,7 @@ pub async fn get_additional_payment_data(
                             // These are filled after calling the processor / connector
                             payment_checks: None,
                             authentication_data: None,
+                            co_badged_card_networks: None,
                         },
                     ))
                 }))
@@ -4398,7 +4797,40 @@ pub async fn get_additional_payment_data(
                 details: Some(open_banking.to_owned().into()),
             })
         }
-        domain::PaymentMethodData::NetworkToken(_) => None,
+        domain::PaymentMethodData::NetworkToken(network_token_data) => Some(
+            get_network_token_additional_payment_data(network_token_data),
+        ),
+    }
+}
+
+/// A card is co-badged when its bin data lists more than one network.
+/// The distinct networks are returned in that case, in the order of the bin data.
+pub fn get_co_badged_card_networks(
+    bin_card_networks: &[common_enums::CardNetwork],
+) -> Option<Vec<common_enums::CardNetwork>> {
+    let mut card_networks = Vec::new();
+    for card_network in bin_card_networks {
+        if !card_networks.contains(card_network) {
+            card_networks.push(card_network.clone());
+        }
+    }
+    (card_networks.len() > 1).then_some(card_networks)
+}
+
+/// Additional payment data for a network token, only the last four digits of the token are retained.
+pub fn get_network_token_additional_payment_data(
+    network_token_data: &domain::NetworkTokenData,
+) -> api_models::payments::AdditionalPaymentData {
+    api_models::payments::AdditionalPaymentData::NetworkToken {
+        details: Some(
+            api_models::payments::additional_info::NetworkTokenAdditionalData {
+                last4: Some(network_token_data.token_number.get_last4()),
+                card_network: network_token_data.card_network.clone(),
+                card_type: network_token_data.card_type.clone(),
+                token_exp_month: Some(network_token_data.token_exp_month.clone()),
+                token_exp_year: Some(network_token_data.token_exp_year.clone()),
+            },
+        ),
     }
 }
 
@@ -5768,3 +6200,19 @@ pub fn validate_platform_fees_for_marketplace(
         }
     }
 }
+
+/// Destination charges move the funds to the reseller's account, so they need a transfer account.
+pub fn validate_payment_charges(
+    payment_charges: &PaymentChargeRequest,
+) -> Result<(), errors::ApiErrorResponse> {
+    match payment_charges.charge_type {
+        api_models::enums::PaymentChargeType::Stripe(
+            api_models::enums::StripeChargeType::Destination,
+        ) if payment_charges.transfer_account_id.trim().is_empty() => {
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "charges.transfer_account_id",
+            })
+        }
+        api_models::enums::PaymentChargeType::Stripe(_) => Ok(()),
+    }
+}
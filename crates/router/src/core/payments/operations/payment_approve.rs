@@ -191,6 +191,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCaptureRequest>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            merchant_connector_account_label: None,
             tax_data: None,
         };
 
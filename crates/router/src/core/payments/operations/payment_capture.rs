@@ -252,6 +252,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            merchant_connector_account_label: None,
             tax_data: None,
         };
 
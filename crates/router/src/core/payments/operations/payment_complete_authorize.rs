@@ -343,6 +343,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             authentication: None,
             recurring_details,
             poll_config: None,
+            merchant_connector_account_label: None,
             tax_data: None,
         };
 
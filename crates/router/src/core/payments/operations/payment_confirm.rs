@@ -728,6 +728,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             authentication: None,
             recurring_details,
             poll_config: None,
+            merchant_connector_account_label: None,
             tax_data: None,
         };
 
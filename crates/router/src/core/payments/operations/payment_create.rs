@@ -551,6 +551,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             authentication: None,
             recurring_details,
             poll_config: None,
+            merchant_connector_account_label: None,
             tax_data: None,
         };
 
@@ -955,6 +956,7 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest, PaymentData<F>> f
         if let Some(charges) = &request.charges {
             let amount = request.amount.get_required_value("amount")?;
             helpers::validate_platform_fees_for_marketplace(amount, charges)?;
+            helpers::validate_payment_charges(charges)?;
         };
 
         let _request_straight_through: Option<api::routing::StraightThroughAlgorithm> = request
@@ -1205,6 +1207,8 @@ impl PaymentCreate {
                 profile_id,
                 shipping_cost: request.shipping_cost,
                 order_tax_amount: None,
+                connector_request_id: None,
+                decline_category: None,
             },
             additional_pm_data,
         ))
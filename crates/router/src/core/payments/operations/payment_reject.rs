@@ -187,6 +187,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, PaymentsCancelRequest> for P
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            merchant_connector_account_label: None,
             tax_data: None,
         };
 
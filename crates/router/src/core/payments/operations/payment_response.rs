@@ -30,7 +30,7 @@ use crate::{
                 self as payments_helpers,
                 update_additional_payment_data_with_connector_response_pm_data,
             },
-            tokenization,
+            tokenization, transformers as payments_transformers,
             types::MultipleCaptureData,
             PaymentData,
         },
@@ -1149,6 +1149,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                     )
                     .await;
 
+                    let decline_category = option_gsm.as_ref().map(|gsm| gsm.decision.clone());
                     let gsm_unified_code =
                         option_gsm.as_ref().and_then(|gsm| gsm.unified_code.clone());
                     let gsm_unified_message = option_gsm.and_then(|gsm| gsm.unified_message);
@@ -1222,6 +1223,8 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             connector_transaction_id: err.connector_transaction_id,
                             payment_method_data: additional_payment_method_data,
                             authentication_type: auth_update,
+                            connector_request_id: router_data.connector_request_id.clone(),
+                            decline_category,
                         }),
                     )
                 }
@@ -1259,6 +1262,8 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             connector_transaction_id,
                             payment_method_data: None,
                             authentication_type: auth_update,
+                            connector_request_id: None,
+                            decline_category: None,
                         }),
                     )
                 }
@@ -1740,6 +1745,21 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     }
     payment_data.payment_intent = payment_intent;
     payment_data.payment_attempt = payment_attempt;
+
+    // When the connector asks for a wait screen, set the poll status in redis to allow the sdk to fetch it through retrieve_poll_status api.
+    // The attempt is already persisted at this point, so a failure here is only logged
+    if payments_transformers::wait_screen_next_steps_check(payment_data.payment_attempt.clone())
+        .map_err(|error| logger::error!(wait_screen_next_steps_error=?error))
+        .ok()
+        .flatten()
+        .is_some()
+    {
+        set_wait_screen_poll_status(state, &payment_data.payment_attempt)
+            .await
+            .map_err(|error| logger::error!(wait_screen_poll_status_error=?error))
+            .ok();
+    }
+
     router_data.payment_method_status.and_then(|status| {
         payment_data
             .payment_method_info
@@ -1891,6 +1911,34 @@ async fn update_payment_method_status_and_ntid<F: Clone>(
     Ok(())
 }
 
+#[cfg(feature = "v1")]
+async fn set_wait_screen_poll_status(
+    state: &SessionState,
+    payment_attempt: &PaymentAttempt,
+) -> RouterResult<()> {
+    let poll_id = core_utils::get_poll_id(
+        &payment_attempt.merchant_id,
+        core_utils::get_wait_screen_request_poll_id(&payment_attempt.payment_id),
+    );
+    let poll_status = match payment_attempt.status {
+        enums::AttemptStatus::Pending | enums::AttemptStatus::AuthenticationPending => {
+            api_models::poll::PollStatus::Pending
+        }
+        _ => api_models::poll::PollStatus::Completed,
+    };
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    redis_conn
+        .set_key_with_expiry(&poll_id, poll_status.to_string(), consts::POLL_ID_TTL)
+        .await
+        .change_context(errors::StorageError::KVError)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add poll_id in redis")
+}
+
 fn response_to_capture_update(
     multiple_capture_data: &MultipleCaptureData,
     response_list: HashMap<String, CaptureSyncResponse>,
@@ -504,6 +504,7 @@ async fn get_tracker_for_sync<
         authentication,
         recurring_details: None,
         poll_config: None,
+        merchant_connector_account_label: None,
         tax_data: None,
     };
 
@@ -158,7 +158,8 @@ where
                         ),
                     }))?
                 }
-                api_models::gsm::GsmDecision::DoDefault => break,
+                api_models::gsm::GsmDecision::DoDefault
+                | api_models::gsm::GsmDecision::HardDecline => break,
             }
             initial_gsm = None;
         }
@@ -352,11 +353,17 @@ where
     D: payments::OperationSessionGetters<F> + payments::OperationSessionSetters<F> + Send + Sync,
 {
     let new_attempt_count = payment_data.get_payment_intent().attempt_count + 1;
+    // Carry the tax over so that the amounts of the payment stay the same across attempts
+    let order_tax_amount = payments::helpers::get_order_tax_amount(
+        payment_data.get_payment_attempt().order_tax_amount,
+        payment_data.get_payment_intent().tax_details.as_ref(),
+    );
     let new_payment_attempt = make_new_payment_attempt(
         connector,
         payment_data.get_payment_attempt().clone(),
         new_attempt_count,
         is_step_up,
+        order_tax_amount,
     );
 
     let db = &*state.store;
@@ -469,10 +476,12 @@ where
                 amount_capturable: Some(MinorUnit::new(0)),
                 updated_by: storage_scheme.to_string(),
                 unified_code: option_gsm.clone().map(|gsm| gsm.unified_code),
+                decline_category: option_gsm.as_ref().map(|gsm| gsm.decision.clone()),
                 unified_message: option_gsm.map(|gsm| gsm.unified_message),
                 connector_transaction_id: error_response.connector_transaction_id.clone(),
                 payment_method_data: additional_payment_method_data,
                 authentication_type: auth_update,
+                connector_request_id: router_data.connector_request_id.clone(),
             };
 
             #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "payment_v2")))]
@@ -546,6 +555,7 @@ pub fn make_new_payment_attempt(
     old_payment_attempt: storage::PaymentAttempt,
     new_attempt_count: i16,
     is_step_up: bool,
+    order_tax_amount: Option<MinorUnit>,
 ) -> storage::PaymentAttemptNew {
     let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
     storage::PaymentAttemptNew {
@@ -614,7 +624,9 @@ pub fn make_new_payment_attempt(
         fingerprint_id: Default::default(),
         charge_id: Default::default(),
         customer_acceptance: Default::default(),
-        order_tax_amount: Default::default(),
+        order_tax_amount,
+        connector_request_id: Default::default(),
+        decline_category: Default::default(),
     }
 }
 
@@ -625,6 +637,7 @@ pub fn make_new_payment_attempt(
     _old_payment_attempt: storage::PaymentAttempt,
     _new_attempt_count: i16,
     _is_step_up: bool,
+    _order_tax_amount: Option<MinorUnit>,
 ) -> storage::PaymentAttempt {
     todo!()
 }
@@ -34,6 +34,8 @@ use rand::{
 use rustc_hash::FxHashMap;
 use storage_impl::redis::cache::{CacheKey, CGRAPH_CACHE, ROUTING_CACHE};
 
+#[cfg(feature = "v1")]
+use crate::connector::utils::CardData;
 #[cfg(feature = "v2")]
 use crate::core::admin;
 #[cfg(feature = "payouts")]
@@ -812,6 +814,92 @@ pub async fn perform_eligibility_analysis_with_fallback(
     Ok(final_selection)
 }
 
+/// Moves the connectors configured in test mode to the front of the list when a test card is used
+/// outside the production environment, so that mixed testing reaches the connector sandbox.
+/// This is enabled per merchant through the `prefer_sandbox_connectors_for_test_card` config.
+#[cfg(feature = "v1")]
+pub async fn prefer_sandbox_connectors_for_test_card(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+    payment_method_data: Option<&domain::PaymentMethodData>,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let is_test_card = matches!(
+        payment_method_data,
+        Some(domain::PaymentMethodData::Card(card)) if card.is_test_card()
+    );
+    if !is_test_card || matches!(router_env::env::which(), router_env::env::Env::Production) {
+        return Ok(connectors);
+    }
+
+    let prefer_sandbox_connectors = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &key_store
+                .merchant_id
+                .get_prefer_sandbox_connectors_for_test_card_key(),
+            Some("false".to_string()),
+        )
+        .await
+        .map(|config| serde_json::from_str::<bool>(&config.config).unwrap_or(false))
+        .unwrap_or(false);
+    if !prefer_sandbox_connectors {
+        return Ok(connectors);
+    }
+
+    // The merchant connector accounts are served from the accounts cache, which is populated
+    // when the connector is called, instead of listing all the accounts of the merchant
+    let mut sandbox_merchant_connector_ids = Vec::new();
+    for merchant_connector_id in connectors
+        .iter()
+        .filter_map(|connector| connector.merchant_connector_id.as_ref())
+    {
+        // The preference is best effort, keep the routing order if an account cannot be fetched
+        let merchant_connector_account = match state
+            .store
+            .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                &state.into(),
+                &key_store.merchant_id,
+                merchant_connector_id,
+                key_store,
+            )
+            .await
+        {
+            Ok(merchant_connector_account) => merchant_connector_account,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch the merchant connector account for sandbox connector preference"
+                );
+                return Ok(connectors);
+            }
+        };
+        if merchant_connector_account.test_mode == Some(true) {
+            sandbox_merchant_connector_ids.push(merchant_connector_id.clone());
+        }
+    }
+
+    Ok(sort_connectors_by_sandbox_preference(
+        connectors,
+        &sandbox_merchant_connector_ids,
+    ))
+}
+
+#[cfg(feature = "v1")]
+fn sort_connectors_by_sandbox_preference(
+    mut connectors: Vec<routing_types::RoutableConnectorChoice>,
+    sandbox_merchant_connector_ids: &[common_utils::id_type::MerchantConnectorAccountId],
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    // The sort is stable, so the routing order is kept within sandbox and live connectors
+    connectors.sort_by_key(|connector| {
+        !connector
+            .merchant_connector_id
+            .as_ref()
+            .is_some_and(|id| sandbox_merchant_connector_ids.contains(id))
+    });
+    connectors
+}
+
 pub async fn perform_session_flow_routing(
     session_input: SessionFlowRoutingInput<'_>,
     transaction_type: &api_enums::TransactionType,
@@ -1202,3 +1290,68 @@ pub fn make_dsl_input_for_surcharge(
     };
     Ok(backend_input)
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_connector_choice(
+        connector: api_enums::RoutableConnectors,
+        merchant_connector_id: &str,
+    ) -> routing_types::RoutableConnectorChoice {
+        routing_types::RoutableConnectorChoice {
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: Some(
+                common_utils::id_type::MerchantConnectorAccountId::wrap(
+                    merchant_connector_id.to_string(),
+                )
+                .unwrap(),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_sort_connectors_by_sandbox_preference() {
+        let connectors = vec![
+            get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_live_stripe"),
+            get_connector_choice(api_enums::RoutableConnectors::Adyen, "mca_sandbox_adyen"),
+            get_connector_choice(api_enums::RoutableConnectors::Checkout, "mca_live_checkout"),
+            get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_sandbox_stripe"),
+        ];
+        let sandbox_merchant_connector_ids = vec![
+            common_utils::id_type::MerchantConnectorAccountId::wrap(
+                "mca_sandbox_stripe".to_string(),
+            )
+            .unwrap(),
+            common_utils::id_type::MerchantConnectorAccountId::wrap(
+                "mca_sandbox_adyen".to_string(),
+            )
+            .unwrap(),
+        ];
+
+        let sorted_connectors =
+            sort_connectors_by_sandbox_preference(connectors, &sandbox_merchant_connector_ids);
+
+        assert_eq!(
+            sorted_connectors,
+            vec![
+                get_connector_choice(api_enums::RoutableConnectors::Adyen, "mca_sandbox_adyen"),
+                get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_sandbox_stripe"),
+                get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_live_stripe"),
+                get_connector_choice(api_enums::RoutableConnectors::Checkout, "mca_live_checkout"),
+            ]
+        );
+
+        let connectors = vec![get_connector_choice(
+            api_enums::RoutableConnectors::Stripe,
+            "mca_live_stripe",
+        )];
+        assert_eq!(
+            sort_connectors_by_sandbox_preference(connectors.clone(), &[]),
+            connectors
+        );
+    }
+}
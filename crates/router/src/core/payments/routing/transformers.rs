@@ -21,7 +21,10 @@ impl ForeignFrom<routing_types::RoutableConnectorChoice> for dsl_ast::ConnectorC
 impl ForeignFrom<storage_enums::CaptureMethod> for Option<dsl_enums::CaptureMethod> {
     fn foreign_from(value: storage_enums::CaptureMethod) -> Self {
         match value {
-            storage_enums::CaptureMethod::Automatic => Some(dsl_enums::CaptureMethod::Automatic),
+            storage_enums::CaptureMethod::Automatic
+            | storage_enums::CaptureMethod::SequentialAutomatic => {
+                Some(dsl_enums::CaptureMethod::Automatic)
+            }
             storage_enums::CaptureMethod::Manual => Some(dsl_enums::CaptureMethod::Manual),
             _ => None,
         }
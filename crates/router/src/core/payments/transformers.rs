@@ -8,18 +8,29 @@ use common_enums::{Currency, RequestIncrementalAuthorization};
 use common_utils::{
     consts::X_HS_LATENCY,
     fp_utils,
-    pii::Email,
+    pii::{self, Email},
     types::{AmountConvertor, MinorUnit, StringMajorUnitForConnector},
 };
 use diesel_models::ephemeral_key;
 use error_stack::{report, ResultExt};
-use hyperswitch_domain_models::{payments::payment_intent::CustomerData, router_request_types};
+use hyperswitch_domain_models::{
+    mandates, payments::payment_intent::CustomerData, router_request_types,
+};
 use masking::{ExposeInterface, Maskable, PeekInterface, Secret};
 use router_env::{instrument, metrics::add_attributes, tracing};
 
-use super::{flows::Feature, types::AuthenticationData, OperationSessionGetters, PaymentData};
+use super::{
+    flows::Feature,
+    types::{AuthenticationData, MultipleCaptureData, SurchargeDetails},
+    OperationSessionGetters, PaymentData,
+};
 use crate::{
-    configs::settings::ConnectorRequestReferenceIdConfig,
+    configs::{
+        settings::{
+            ConnectorRequestReferenceIdConfig, Connectors, LatencyHeaderConfig, ManualRetryConfig,
+        },
+        Settings,
+    },
     connector::{Helcim, Nexinets},
     core::{
         errors::{self, RouterResponse, RouterResult},
@@ -33,7 +44,7 @@ use crate::{
         self,
         api::{self, ConnectorTransactionId},
         domain,
-        storage::{self, enums},
+        storage::{self, enums, payment_attempt::PaymentAttemptExt},
         transformers::{ForeignFrom, ForeignInto, ForeignTryFrom},
         MultipleCaptureRequestData,
     },
@@ -47,7 +58,7 @@ pub async fn construct_router_data_to_update_calculated_tax<'a, F, T>(
     merchant_account: &domain::MerchantAccount,
     _key_store: &domain::MerchantKeyStore,
     customer: &'a Option<domain::Customer>,
-    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    merchant_connector_account: &'a helpers::MerchantConnectorAccountType,
 ) -> RouterResult<types::RouterData<F, T, types::PaymentsResponseData>>
 where
     T: TryFrom<PaymentAdditionalData<'a, F>>,
@@ -56,105 +67,213 @@ where
     error_stack::Report<errors::ApiErrorResponse>:
         From<<T as TryFrom<PaymentAdditionalData<'a, F>>>::Error>,
 {
-    fp_utils::when(merchant_connector_account.is_disabled(), || {
-        Err(errors::ApiErrorResponse::MerchantConnectorAccountDisabled)
-    })?;
-
-    let test_mode = merchant_connector_account.is_test_mode_on();
-
-    let auth_type: types::ConnectorAuthType = merchant_connector_account
-        .get_connector_account_details()
-        .parse_value("ConnectorAuthType")
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed while parsing value for ConnectorAuthType")?;
-
-    let resource_id = match payment_data
-        .payment_attempt
-        .connector_transaction_id
-        .clone()
-    {
-        Some(id) => types::ResponseId::ConnectorTransactionId(id),
-        None => types::ResponseId::NoResponseId,
-    };
+    let router_data_builder =
+        PaymentRouterDataBuilder::new(merchant_connector_account, connector_id)?;
 
-    // [#44]: why should response be filled during request
-    let response = Ok(types::PaymentsResponseData::TransactionResponse {
-        resource_id,
-        redirection_data: None,
-        mandate_reference: None,
-        connector_metadata: None,
-        network_txn_id: None,
-        connector_response_reference_id: None,
-        incremental_authorization_allowed: None,
-        charge_id: None,
-    });
     let additional_data = PaymentAdditionalData {
         router_base_url: state.base_url.clone(),
         connector_name: connector_id.to_string(),
         payment_data: payment_data.clone(),
-        state,
+        connectors: &state.conf.connectors,
         customer_data: customer,
+        merchant_connector_account,
     };
 
-    let router_data = types::RouterData {
-        flow: PhantomData,
-        merchant_id: merchant_account.get_id().clone(),
-        customer_id: None,
-        connector: connector_id.to_owned(),
-        payment_id: payment_data
-            .payment_attempt
-            .payment_id
-            .get_string_repr()
-            .to_owned(),
-        attempt_id: payment_data.payment_attempt.attempt_id.clone(),
-        status: payment_data.payment_attempt.status,
-        payment_method: diesel_models::enums::PaymentMethod::default(),
-        connector_auth_type: auth_type,
-        description: None,
-        return_url: None,
+    let overrides = PaymentRouterDataOverrides {
+        customer_id: customer
+            .as_ref()
+            .map(|customer| customer.customer_id.clone()),
         address: payment_data.address.clone(),
-        auth_type: payment_data
-            .payment_attempt
-            .authentication_type
-            .unwrap_or_default(),
-        connector_meta_data: None,
-        connector_wallets_details: None,
-        request: T::try_from(additional_data)?,
-        response,
-        amount_captured: None,
-        minor_amount_captured: None,
-        access_token: None,
-        session_token: None,
-        reference_id: None,
-        payment_method_status: None,
-        payment_method_token: None,
-        connector_customer: None,
-        recurring_mandate_payment_data: None,
-        connector_request_reference_id: core_utils::get_connector_request_reference_id(
-            &state.conf,
-            merchant_account.get_id(),
-            &payment_data.payment_attempt,
-        ),
-        preprocessing_id: None,
-        #[cfg(feature = "payouts")]
-        payout_method_data: None,
-        #[cfg(feature = "payouts")]
-        quote_id: None,
-        test_mode,
-        payment_method_balance: None,
-        connector_api_version: None,
-        connector_http_status_code: None,
-        external_latency: None,
-        apple_pay_flow: None,
-        frm_metadata: None,
-        refund_id: None,
-        dispute_id: None,
-        connector_response: None,
-        integrity_check: Ok(()),
-        additional_merchant_data: None,
-        header_payload: None,
+        ..Default::default()
+    };
+
+    Ok(router_data_builder.build(
+        &state.conf,
+        merchant_account.get_id(),
+        connector_id,
+        &payment_data.payment_attempt,
+        T::try_from(additional_data)?,
+        overrides,
+    ))
+}
+
+/// Flow specific fields of the payment router data, the fields left to default are not sent to
+/// the connector.
+#[derive(Default)]
+struct PaymentRouterDataOverrides {
+    customer_id: Option<common_utils::id_type::CustomerId>,
+    payment_method: enums::PaymentMethod,
+    description: Option<String>,
+    return_url: Option<String>,
+    address: types::PaymentAddress,
+    connector_meta_data: Option<pii::SecretSerdeValue>,
+    connector_wallets_details: Option<pii::SecretSerdeValue>,
+    amount_captured: Option<i64>,
+    minor_amount_captured: Option<MinorUnit>,
+    payment_method_status: Option<common_enums::PaymentMethodStatus>,
+    payment_method_token: Option<types::PaymentMethodToken>,
+    connector_customer: Option<String>,
+    recurring_mandate_payment_data: Option<types::RecurringMandatePaymentData>,
+    preprocessing_id: Option<String>,
+    connector_api_version: Option<String>,
+    apple_pay_flow: Option<domain::ApplePayFlow>,
+    additional_merchant_data: Option<api_models::admin::AdditionalMerchantData>,
+    header_payload: Option<api_models::payments::HeaderPayload>,
+}
+
+/// Scaffolding shared by the flows constructing payment router data, so that the fields derived
+/// from the merchant connector account and the payment attempt are filled the same way everywhere.
+struct PaymentRouterDataBuilder {
+    test_mode: Option<bool>,
+    connector_auth_type: types::ConnectorAuthType,
+}
+
+impl PaymentRouterDataBuilder {
+    fn new(
+        merchant_connector_account: &helpers::MerchantConnectorAccountType,
+        connector_id: &str,
+    ) -> RouterResult<Self> {
+        fp_utils::when(merchant_connector_account.is_disabled(), || {
+            Err(errors::ApiErrorResponse::MerchantConnectorAccountDisabled)
+        })?;
+
+        let connector_auth_type = get_connector_auth_type(
+            merchant_connector_account.get_connector_account_details(),
+            connector_id,
+        )?;
+
+        Ok(Self {
+            test_mode: merchant_connector_account.is_test_mode_on(),
+            connector_auth_type,
+        })
+    }
+
+    fn build<F, T>(
+        self,
+        conf: &Settings,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector_id: &str,
+        payment_attempt: &storage::PaymentAttempt,
+        request: T,
+        overrides: PaymentRouterDataOverrides,
+    ) -> types::RouterData<F, T, types::PaymentsResponseData> {
+        let resource_id = match payment_attempt.connector_transaction_id.clone() {
+            Some(id) => types::ResponseId::ConnectorTransactionId(id),
+            None => types::ResponseId::NoResponseId,
+        };
+
+        // [#44]: why should response be filled during request
+        let response = Ok(types::PaymentsResponseData::TransactionResponse {
+            resource_id,
+            redirection_data: None,
+            mandate_reference: None,
+            connector_metadata: None,
+            network_txn_id: None,
+            connector_response_reference_id: None,
+            incremental_authorization_allowed: None,
+            charge_id: None,
+        });
+
+        types::RouterData {
+            flow: PhantomData,
+            merchant_id: merchant_id.clone(),
+            customer_id: overrides.customer_id,
+            connector: connector_id.to_owned(),
+            payment_id: payment_attempt.payment_id.get_string_repr().to_owned(),
+            attempt_id: payment_attempt.attempt_id.clone(),
+            status: payment_attempt.status,
+            payment_method: overrides.payment_method,
+            connector_auth_type: self.connector_auth_type,
+            description: overrides.description,
+            return_url: overrides.return_url,
+            address: overrides.address,
+            auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+            connector_meta_data: overrides.connector_meta_data,
+            connector_wallets_details: overrides.connector_wallets_details,
+            request,
+            response,
+            amount_captured: overrides.amount_captured,
+            minor_amount_captured: overrides.minor_amount_captured,
+            access_token: None,
+            session_token: None,
+            reference_id: None,
+            payment_method_status: overrides.payment_method_status,
+            payment_method_token: overrides.payment_method_token,
+            connector_customer: overrides.connector_customer,
+            recurring_mandate_payment_data: overrides.recurring_mandate_payment_data,
+            connector_request_reference_id: core_utils::get_connector_request_reference_id(
+                conf,
+                merchant_id,
+                payment_attempt,
+            ),
+            preprocessing_id: overrides.preprocessing_id,
+            #[cfg(feature = "payouts")]
+            payout_method_data: None,
+            #[cfg(feature = "payouts")]
+            quote_id: None,
+            test_mode: self.test_mode,
+            payment_method_balance: None,
+            connector_api_version: overrides.connector_api_version,
+            connector_http_status_code: None,
+            external_latency: None,
+            connector_request_id: None,
+            apple_pay_flow: overrides.apple_pay_flow,
+            frm_metadata: None,
+            refund_id: None,
+            dispute_id: None,
+            connector_response: None,
+            integrity_check: Ok(()),
+            additional_merchant_data: overrides.additional_merchant_data,
+            header_payload: overrides.header_payload,
+        }
+    }
+}
+
+/// Merges the billing address saved with the payment method into the payment address.
+/// Fields already present on the payment address take precedence, the saved billing only fills the empty ones.
+pub fn unify_address(
+    payment_address: &types::PaymentAddress,
+    payment_method_billing: Option<Address>,
+) -> types::PaymentAddress {
+    payment_address
+        .clone()
+        .unify_with_payment_data_billing(payment_method_billing)
+}
+
+/// Describes the billing and shipping addresses using only the fields that are safe to be logged,
+/// the country and the first few characters of the zip code.
+pub fn get_loggable_address_details(payment_address: &types::PaymentAddress) -> String {
+    let describe = |address: Option<&Address>| {
+        let address_details = address.and_then(|address| address.address.as_ref());
+        let country = address_details.and_then(|address_details| address_details.country);
+        let zip_prefix = address_details
+            .and_then(|address_details| address_details.zip.as_ref())
+            .map(|zip| zip.peek().chars().take(3).collect::<String>());
+        format!("country: {country:?}, zip_prefix: {zip_prefix:?}")
     };
-    Ok(router_data)
+    format!(
+        "billing: {{ {} }}, shipping: {{ {} }}",
+        describe(payment_address.get_payment_method_billing()),
+        describe(payment_address.get_shipping())
+    )
+}
+
+/// Parses the credentials of the merchant connector account, naming the connector on failure so
+/// that the misconfigured account can be identified.
+pub fn get_connector_auth_type(
+    connector_account_details: serde_json::Value,
+    connector_name: &str,
+) -> RouterResult<types::ConnectorAuthType> {
+    connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InvalidConnectorConfiguration {
+            config: "connector_account_details".to_string(),
+        })
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while parsing value for ConnectorAuthType of connector {connector_name}"
+            )
+        })
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -191,7 +310,7 @@ pub async fn construct_payment_router_data<'a, F, T>(
     merchant_account: &domain::MerchantAccount,
     _key_store: &domain::MerchantKeyStore,
     customer: &'a Option<domain::Customer>,
-    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    merchant_connector_account: &'a helpers::MerchantConnectorAccountType,
     merchant_recipient_data: Option<types::MerchantRecipientData>,
     header_payload: Option<api_models::payments::HeaderPayload>,
 ) -> RouterResult<types::RouterData<F, T, types::PaymentsResponseData>>
@@ -202,53 +321,22 @@ where
     error_stack::Report<errors::ApiErrorResponse>:
         From<<T as TryFrom<PaymentAdditionalData<'a, F>>>::Error>,
 {
-    let (payment_method, router_data);
-
-    fp_utils::when(merchant_connector_account.is_disabled(), || {
-        Err(errors::ApiErrorResponse::MerchantConnectorAccountDisabled)
-    })?;
-
-    let test_mode = merchant_connector_account.is_test_mode_on();
-
-    let auth_type: types::ConnectorAuthType = merchant_connector_account
-        .get_connector_account_details()
-        .parse_value("ConnectorAuthType")
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed while parsing value for ConnectorAuthType")?;
+    let router_data_builder =
+        PaymentRouterDataBuilder::new(merchant_connector_account, connector_id)?;
 
-    payment_method = payment_data
+    let payment_method = payment_data
         .payment_attempt
         .payment_method
         .or(payment_data.payment_attempt.payment_method)
         .get_required_value("payment_method_type")?;
 
-    let resource_id = match payment_data
-        .payment_attempt
-        .connector_transaction_id
-        .clone()
-    {
-        Some(id) => types::ResponseId::ConnectorTransactionId(id),
-        None => types::ResponseId::NoResponseId,
-    };
-
-    // [#44]: why should response be filled during request
-    let response = Ok(types::PaymentsResponseData::TransactionResponse {
-        resource_id,
-        redirection_data: None,
-        mandate_reference: None,
-        connector_metadata: None,
-        network_txn_id: None,
-        connector_response_reference_id: None,
-        incremental_authorization_allowed: None,
-        charge_id: None,
-    });
-
     let additional_data = PaymentAdditionalData {
         router_base_url: state.base_url.clone(),
         connector_name: connector_id.to_string(),
         payment_data: payment_data.clone(),
-        state,
+        connectors: &state.conf.connectors,
         customer_data: customer,
+        merchant_connector_account,
     };
 
     let customer_id = customer.to_owned().map(|customer| customer.customer_id);
@@ -281,86 +369,49 @@ where
         Some(merchant_connector_account),
     );
 
-    let unified_address = if let Some(payment_method_info) =
-        payment_data.payment_method_info.clone()
-    {
-        let payment_method_billing = payment_method_info
-            .payment_method_billing_address
-            .map(|decrypted_data| decrypted_data.into_inner().expose())
-            .map(|decrypted_value| decrypted_value.parse_value("payment_method_billing_address"))
-            .transpose()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("unable to parse payment_method_billing_address")?;
-        payment_data
-            .address
-            .clone()
-            .unify_with_payment_data_billing(payment_method_billing)
-    } else {
-        payment_data.address
-    };
+    let payment_method_billing = payment_data
+        .payment_method_info
+        .as_ref()
+        .and_then(|payment_method_info| payment_method_info.payment_method_billing_address.clone())
+        .map(|decrypted_data| decrypted_data.into_inner().expose())
+        .map(|decrypted_value| decrypted_value.parse_value("payment_method_billing_address"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse payment_method_billing_address")?;
+
+    let unified_address = unify_address(&payment_data.address, payment_method_billing);
 
-    crate::logger::debug!("unified address details {:?}", unified_address);
+    crate::logger::debug!(
+        "unified address details {}",
+        get_loggable_address_details(&unified_address)
+    );
 
-    router_data = types::RouterData {
-        flow: PhantomData,
-        merchant_id: merchant_account.get_id().clone(),
+    let overrides = PaymentRouterDataOverrides {
         customer_id,
-        connector: connector_id.to_owned(),
-        payment_id: payment_data
-            .payment_attempt
-            .payment_id
-            .get_string_repr()
-            .to_owned(),
-        attempt_id: payment_data.payment_attempt.attempt_id.clone(),
-        status: payment_data.payment_attempt.status,
         payment_method,
-        connector_auth_type: auth_type,
         description: payment_data.payment_intent.description.clone(),
         return_url: payment_data.payment_intent.return_url.clone(),
         address: unified_address,
-        auth_type: payment_data
-            .payment_attempt
-            .authentication_type
-            .unwrap_or_default(),
         connector_meta_data: merchant_connector_account.get_metadata(),
         connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
-        request: T::try_from(additional_data)?,
-        response,
         amount_captured: payment_data
             .payment_intent
             .amount_captured
             .map(|amt| amt.get_amount_as_i64()),
         minor_amount_captured: payment_data.payment_intent.amount_captured,
-        access_token: None,
-        session_token: None,
-        reference_id: None,
-        payment_method_status: payment_data.payment_method_info.map(|info| info.status),
+        payment_method_status: payment_data
+            .payment_method_info
+            .as_ref()
+            .map(|info| info.status),
         payment_method_token: payment_data
             .pm_token
+            .clone()
             .map(|token| types::PaymentMethodToken::Token(Secret::new(token))),
-        connector_customer: payment_data.connector_customer_id,
-        recurring_mandate_payment_data: payment_data.recurring_mandate_payment_data,
-        connector_request_reference_id: core_utils::get_connector_request_reference_id(
-            &state.conf,
-            merchant_account.get_id(),
-            &payment_data.payment_attempt,
-        ),
-        preprocessing_id: payment_data.payment_attempt.preprocessing_step_id,
-        #[cfg(feature = "payouts")]
-        payout_method_data: None,
-        #[cfg(feature = "payouts")]
-        quote_id: None,
-        test_mode,
-        payment_method_balance: None,
+        connector_customer: payment_data.connector_customer_id.clone(),
+        recurring_mandate_payment_data: payment_data.recurring_mandate_payment_data.clone(),
+        preprocessing_id: payment_data.payment_attempt.preprocessing_step_id.clone(),
         connector_api_version,
-        connector_http_status_code: None,
-        external_latency: None,
         apple_pay_flow,
-        frm_metadata: None,
-        refund_id: None,
-        dispute_id: None,
-        connector_response: None,
-        integrity_check: Ok(()),
         additional_merchant_data: merchant_recipient_data.map(|data| {
             api_models::admin::AdditionalMerchantData::foreign_from(
                 types::AdditionalMerchantData::OpenBankingRecipientData(data),
@@ -369,7 +420,14 @@ where
         header_payload,
     };
 
-    Ok(router_data)
+    Ok(router_data_builder.build(
+        &state.conf,
+        merchant_account.get_id(),
+        connector_id,
+        &payment_data.payment_attempt,
+        T::try_from(additional_data)?,
+        overrides,
+    ))
 }
 
 pub trait ToResponse<F, D, Op>
@@ -386,6 +444,8 @@ where
         base_url: &str,
         operation: Op,
         connector_request_reference_id_config: &ConnectorRequestReferenceIdConfig,
+        manual_retry_config: &ManualRetryConfig,
+        latency_header_config: &LatencyHeaderConfig,
         connector_http_status_code: Option<u16>,
         external_latency: Option<u128>,
         is_latency_header_enabled: Option<bool>,
@@ -406,6 +466,8 @@ where
         base_url: &str,
         operation: Op,
         connector_request_reference_id_config: &ConnectorRequestReferenceIdConfig,
+        manual_retry_config: &ManualRetryConfig,
+        latency_header_config: &LatencyHeaderConfig,
         connector_http_status_code: Option<u16>,
         external_latency: Option<u128>,
         is_latency_header_enabled: Option<bool>,
@@ -434,6 +496,8 @@ where
             base_url,
             &operation,
             connector_request_reference_id_config,
+            manual_retry_config,
+            latency_header_config,
             connector_http_status_code,
             external_latency,
             is_latency_header_enabled,
@@ -456,6 +520,8 @@ where
         _base_url: &str,
         _operation: Op,
         _connector_request_reference_id_config: &ConnectorRequestReferenceIdConfig,
+        _manual_retry_config: &ManualRetryConfig,
+        _latency_header_config: &LatencyHeaderConfig,
         _connector_http_status_code: Option<u16>,
         _external_latency: Option<u128>,
         _is_latency_header_enabled: Option<bool>,
@@ -491,15 +557,13 @@ where
         _base_url: &str,
         _operation: Op,
         _connector_request_reference_id_config: &ConnectorRequestReferenceIdConfig,
+        _manual_retry_config: &ManualRetryConfig,
+        _latency_header_config: &LatencyHeaderConfig,
         _connector_http_status_code: Option<u16>,
         _external_latency: Option<u128>,
         _is_latency_header_enabled: Option<bool>,
     ) -> RouterResponse<Self> {
-        let mut amount = payment_data.get_payment_intent().amount;
         let shipping_cost = payment_data.get_payment_intent().shipping_cost;
-        if let Some(shipping_cost) = shipping_cost {
-            amount = amount + shipping_cost;
-        }
         let order_tax_amount = payment_data
             .get_payment_intent()
             .tax_details
@@ -509,9 +573,10 @@ where
                     .map(|a| a.order_tax_amount)
                     .or_else(|| tax.default.map(|a| a.order_tax_amount))
             });
-        if let Some(tax_amount) = order_tax_amount {
-            amount = amount + tax_amount;
-        }
+        let amount = get_net_amount_with_overflow_check(
+            payment_data.get_payment_intent().amount,
+            &[shipping_cost, order_tax_amount],
+        )?;
 
         let currency = payment_data
             .get_payment_attempt()
@@ -536,6 +601,23 @@ where
     }
 }
 
+/// Adds the additional amounts (shipping cost, taxes etc.) to the amount,
+/// failing instead of wrapping around when the sum does not fit in an i64.
+pub fn get_net_amount_with_overflow_check(
+    amount: MinorUnit,
+    additional_amounts: &[Option<MinorUnit>],
+) -> RouterResult<MinorUnit> {
+    additional_amounts
+        .iter()
+        .flatten()
+        .try_fold(amount, |net_amount, additional_amount| {
+            net_amount.checked_add(*additional_amount)
+        })
+        .ok_or(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "net amount exceeds the maximum supported amount".to_string(),
+        }))
+}
+
 impl ForeignTryFrom<(MinorUnit, Option<MinorUnit>, Option<MinorUnit>, Currency)>
     for api_models::payments::DisplayAmountOnSdk
 {
@@ -548,6 +630,30 @@ impl ForeignTryFrom<(MinorUnit, Option<MinorUnit>, Option<MinorUnit>, Currency)>
             Option<MinorUnit>,
             Currency,
         ),
+    ) -> Result<Self, Self::Error> {
+        Self::foreign_try_from((net_amount, shipping_cost, order_tax_amount, None, currency))
+    }
+}
+
+impl
+    ForeignTryFrom<(
+        MinorUnit,
+        Option<MinorUnit>,
+        Option<MinorUnit>,
+        Option<MinorUnit>,
+        Currency,
+    )> for api_models::payments::DisplayAmountOnSdk
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(
+        (net_amount, shipping_cost, order_tax_amount, surcharge_amount, currency): (
+            MinorUnit,
+            Option<MinorUnit>,
+            Option<MinorUnit>,
+            Option<MinorUnit>,
+            Currency,
+        ),
     ) -> Result<Self, Self::Error> {
         let major_unit_convertor = StringMajorUnitForConnector;
 
@@ -579,10 +685,22 @@ impl ForeignTryFrom<(MinorUnit, Option<MinorUnit>, Option<MinorUnit>, Currency)>
                     .attach_printable("Failed to convert order_tax_amount to string major unit")
             })
             .transpose()?;
+
+        let sdk_surcharge_amount = surcharge_amount
+            .map(|surcharge| {
+                major_unit_convertor
+                    .convert(surcharge, currency)
+                    .change_context(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "Failed to convert surcharge_amount to base unit".to_string(),
+                    })
+                    .attach_printable("Failed to convert surcharge_amount to string major unit")
+            })
+            .transpose()?;
         Ok(Self {
             net_amount: sdk_net_amount,
             shipping_cost: sdk_shipping_cost,
             order_tax_amount: sdk_order_tax_amount,
+            surcharge_amount: sdk_surcharge_amount,
         })
     }
 }
@@ -602,6 +720,8 @@ where
         _base_url: &str,
         _operation: Op,
         _connector_request_reference_id_config: &ConnectorRequestReferenceIdConfig,
+        _manual_retry_config: &ManualRetryConfig,
+        _latency_header_config: &LatencyHeaderConfig,
         _connector_http_status_code: Option<u16>,
         _external_latency: Option<u128>,
         _is_latency_header_enabled: Option<bool>,
@@ -618,6 +738,8 @@ where
         _base_url: &str,
         _operation: Op,
         _connector_request_reference_id_config: &ConnectorRequestReferenceIdConfig,
+        _manual_retry_config: &ManualRetryConfig,
+        _latency_header_config: &LatencyHeaderConfig,
         _connector_http_status_code: Option<u16>,
         _external_latency: Option<u128>,
         _is_latency_header_enabled: Option<bool>,
@@ -680,6 +802,8 @@ pub fn payments_to_payments_response<Op, F: Clone, D>(
     _base_url: &str,
     _operation: &Op,
     _connector_request_reference_id_config: &ConnectorRequestReferenceIdConfig,
+    _manual_retry_config: &ManualRetryConfig,
+    _latency_header_config: &LatencyHeaderConfig,
     _connector_http_status_code: Option<u16>,
     _external_latency: Option<u128>,
     _is_latency_header_enabled: Option<bool>,
@@ -704,6 +828,8 @@ pub fn payments_to_payments_response<Op, F: Clone, D>(
     base_url: &str,
     operation: &Op,
     connector_request_reference_id_config: &ConnectorRequestReferenceIdConfig,
+    manual_retry_config: &ManualRetryConfig,
+    latency_header_config: &LatencyHeaderConfig,
     connector_http_status_code: Option<u16>,
     external_latency: Option<u128>,
     _is_latency_header_enabled: Option<bool>,
@@ -717,6 +843,11 @@ where
     let payment_attempt = payment_data.get_payment_attempt().clone();
     let payment_intent = payment_data.get_payment_intent().clone();
     let payment_link_data = payment_data.get_payment_link_data();
+    let payment_link_expired = is_payment_link_expired(
+        payment_link_data.as_ref(),
+        payment_intent.session_expiry,
+        payment_intent.created_at,
+    );
 
     let currency = payment_attempt
         .currency
@@ -730,16 +861,14 @@ where
     let mandate_id = payment_attempt.mandate_id.clone();
 
     let refunds_response = payment_data.get_refunds().is_empty().not().then(|| {
-        payment_data
-            .get_refunds()
+        sort_by_created_at_desc(payment_data.get_refunds(), |refund| refund.created_at)
             .into_iter()
             .map(ForeignInto::foreign_into)
             .collect()
     });
 
     let disputes_response = payment_data.get_disputes().is_empty().not().then(|| {
-        payment_data
-            .get_disputes()
+        sort_by_created_at_desc(payment_data.get_disputes(), |dispute| dispute.created_at)
             .into_iter()
             .map(ForeignInto::foreign_into)
             .collect()
@@ -757,6 +886,7 @@ where
     let external_authentication_details = payment_data
         .get_authentication()
         .map(ForeignInto::foreign_into);
+    let cavv_present = is_cavv_present(payment_data.get_authentication());
 
     let attempts_response = payment_data.get_attempts().map(|attempts| {
         attempts
@@ -766,7 +896,7 @@ where
     });
 
     let captures_response = captures.map(|captures| {
-        captures
+        sort_by_created_at_desc(captures, |capture| capture.created_at)
             .into_iter()
             .map(ForeignInto::foreign_into)
             .collect()
@@ -783,27 +913,25 @@ where
         .as_ref()
         .map(ToString::to_string)
         .unwrap_or("".to_owned());
-    let additional_payment_method_data: Option<api_models::payments::AdditionalPaymentData> =
-        payment_attempt
-            .payment_method_data
-            .clone()
-            .and_then(|data| match data {
-                serde_json::Value::Null => None, // This is to handle the case when the payment_method_data is null
-                _ => Some(data.parse_value("AdditionalPaymentData")),
-            })
-            .transpose()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to parse the AdditionalPaymentData from payment_attempt.payment_method_data")?;
+    let additional_payment_method_data = get_additional_payment_method_data(&payment_attempt)?;
 
     let surcharge_details =
-        payment_attempt
-            .surcharge_amount
-            .map(|surcharge_amount| RequestSurchargeDetails {
-                surcharge_amount,
-                tax_amount: payment_attempt.tax_amount,
-            });
+        get_request_surcharge_details(payment_data.surcharge_details.as_ref(), &payment_attempt);
+    let amount_excluding_surcharge = get_amount_excluding_surcharge(
+        payment_attempt.net_amount,
+        surcharge_details
+            .as_ref()
+            .map(|surcharge_details| surcharge_details.surcharge_amount),
+        surcharge_details
+            .as_ref()
+            .and_then(|surcharge_details| surcharge_details.tax_amount),
+    );
     let merchant_decision = payment_intent.merchant_decision.to_owned();
     let frm_message = payment_data.get_frm_message().map(FrmMessage::foreign_from);
+    let risk_score = frm_message.as_ref().and_then(FrmMessage::get_risk_score);
+    let risk_decision = frm_message.as_ref().and_then(FrmMessage::get_risk_decision);
+    let (selected_card_network, available_card_networks) =
+        get_card_network_details(additional_payment_method_data.as_ref());
 
     let payment_method_data =
         additional_payment_method_data.map(api::PaymentMethodDataResponse::from);
@@ -842,58 +970,17 @@ where
 
     // If we have customer data in Payment Intent and if the customer is not deleted, We are populating the Retrieve response from the
     // same. If the customer is deleted then we use the customer table to populate customer details
-    let customer_details_response =
-        if let Some(customer_details_raw) = payment_intent.customer_details.clone() {
-            let customer_details_encrypted =
-                serde_json::from_value::<CustomerData>(customer_details_raw.into_inner().expose());
-            if let Ok(customer_details_encrypted_data) = customer_details_encrypted {
-                Some(CustomerDetailsResponse {
-                    id: customer_table_response
-                        .as_ref()
-                        .and_then(|customer_data| customer_data.id.clone()),
-                    name: customer_table_response
-                        .as_ref()
-                        .and_then(|customer_data| customer_data.name.clone())
-                        .or(customer_details_encrypted_data
-                            .name
-                            .or(customer.as_ref().and_then(|customer| {
-                                customer.name.as_ref().map(|name| name.clone().into_inner())
-                            }))),
-                    email: customer_table_response
-                        .as_ref()
-                        .and_then(|customer_data| customer_data.email.clone())
-                        .or(customer_details_encrypted_data.email.or(customer
-                            .as_ref()
-                            .and_then(|customer| customer.email.clone().map(Email::from)))),
-                    phone: customer_table_response
-                        .as_ref()
-                        .and_then(|customer_data| customer_data.phone.clone())
-                        .or(customer_details_encrypted_data
-                            .phone
-                            .or(customer.as_ref().and_then(|customer| {
-                                customer
-                                    .phone
-                                    .as_ref()
-                                    .map(|phone| phone.clone().into_inner())
-                            }))),
-                    phone_country_code: customer_table_response
-                        .as_ref()
-                        .and_then(|customer_data| customer_data.phone_country_code.clone())
-                        .or(customer_details_encrypted_data
-                            .phone_country_code
-                            .or(customer
-                                .as_ref()
-                                .and_then(|customer| customer.phone_country_code.clone()))),
-                })
-            } else {
-                customer_table_response
-            }
-        } else {
-            customer_table_response
-        };
+    let customer_details_response = build_customer_details_response(
+        customer.as_ref(),
+        payment_intent
+            .customer_details
+            .clone()
+            .map(|customer_details| customer_details.into_inner().expose()),
+        customer_table_response,
+    );
 
     headers.extend(
-        external_latency
+        get_latency_to_report(external_latency, latency_header_config)
             .map(|latency| {
                 vec![(
                     X_HS_LATENCY.to_string(),
@@ -937,11 +1024,15 @@ where
         let next_action_containing_wait_screen =
             wait_screen_next_steps_check(payment_attempt.clone())?;
 
+        let next_action_containing_open_banking_consent =
+            open_banking_consent_next_steps_check(payment_attempt.clone())?;
+
         if payment_intent.status == enums::IntentStatus::RequiresCustomerAction
             || bank_transfer_next_steps.is_some()
             || next_action_voucher.is_some()
             || next_action_containing_qr_code_url.is_some()
             || next_action_containing_wait_screen.is_some()
+            || next_action_containing_open_banking_consent.is_some()
             || papal_sdk_next_action.is_some()
             || next_action_containing_fetch_qr_code_url.is_some()
             || payment_data.get_authentication().is_some()
@@ -962,7 +1053,8 @@ where
                         }))
                         .or(next_action_containing_fetch_qr_code_url.map(|fetch_qr_code_data| {
                             api_models::payments::NextActionData::FetchQrCodeInformation {
-                                qr_code_fetch_url: fetch_qr_code_data.qr_code_fetch_url
+                                qr_code_fetch_url: fetch_qr_code_data.qr_code_fetch_url,
+                                display_to_timestamp: fetch_qr_code_data.display_to_timestamp,
                             }
                         }))
                         .or(papal_sdk_next_action.map(|paypal_next_action_data| {
@@ -971,9 +1063,18 @@ where
                             }
                         }))
                         .or(next_action_containing_wait_screen.map(|wait_screen_data| {
-                            api_models::payments::NextActionData::WaitScreenInformation {
-                                display_from_timestamp: wait_screen_data.display_from_timestamp,
-                                display_to_timestamp: wait_screen_data.display_to_timestamp,
+                            get_wait_screen_next_action(
+                                wait_screen_data,
+                                payment_data.get_poll_config(),
+                                core_utils::get_wait_screen_request_poll_id(
+                                    &payment_intent.payment_id,
+                                ),
+                            )
+                        }))
+                        .or(next_action_containing_open_banking_consent.map(|open_banking_consent| {
+                            api_models::payments::NextActionData::OpenBankingConsent {
+                                consent_url: open_banking_consent.consent_url,
+                                expires_at: open_banking_consent.expires_at,
                             }
                         }))
                         .or(payment_attempt.authentication_data.as_ref().map(|_| {
@@ -1038,14 +1139,29 @@ where
 
         let routed_through = payment_attempt.connector.clone();
 
-        let connector_label = routed_through.as_ref().and_then(|connector_name| {
-            core_utils::get_connector_label(
-                payment_intent.business_country,
-                payment_intent.business_label.as_ref(),
-                payment_attempt.business_sub_label.as_ref(),
-                connector_name,
-            )
-        });
+        let connector_label = get_connector_label_from_mca(
+            payment_attempt.merchant_connector_id.as_ref(),
+            payment_data.get_merchant_connector_account_label(),
+            || {
+                routed_through.as_ref().and_then(|connector_name| {
+                    core_utils::get_connector_label(
+                        payment_intent.business_country,
+                        payment_intent.business_label.as_ref(),
+                        payment_attempt.business_sub_label.as_ref(),
+                        connector_name,
+                    )
+                })
+            },
+        );
+
+        let bnpl_order_status = bnpl_order_status_check(&payment_attempt);
+        let amount_capturable =
+            get_amount_capturable(&payment_attempt, payment_data.get_multiple_capture_data());
+        let capture_executed = helpers::get_capture_executed(
+            &payment_attempt.status,
+            payment_attempt.capture_method,
+            payment_data.get_multiple_capture_data().is_some(),
+        );
 
         let charges_response = match payment_intent.charges {
             None => None,
@@ -1069,65 +1185,29 @@ where
             }
         };
 
-        let mandate_data = payment_data.get_setup_mandate().map(|d| api::MandateData {
-            customer_acceptance: d
-                .customer_acceptance
-                .clone()
-                .map(|d| api::CustomerAcceptance {
-                    acceptance_type: match d.acceptance_type {
-                        hyperswitch_domain_models::mandates::AcceptanceType::Online => {
-                            api::AcceptanceType::Online
-                        }
-                        hyperswitch_domain_models::mandates::AcceptanceType::Offline => {
-                            api::AcceptanceType::Offline
-                        }
-                    },
-                    accepted_at: d.accepted_at,
-                    online: d.online.map(|d| api::OnlineMandate {
-                        ip_address: d.ip_address,
-                        user_agent: d.user_agent,
-                    }),
-                }),
-            mandate_type: d.mandate_type.clone().map(|d| match d {
-                hyperswitch_domain_models::mandates::MandateDataType::MultiUse(Some(i)) => {
-                    api::MandateType::MultiUse(Some(api::MandateAmountData {
-                        amount: i.amount,
-                        currency: i.currency,
-                        start_date: i.start_date,
-                        end_date: i.end_date,
-                        metadata: i.metadata,
-                    }))
-                }
-                hyperswitch_domain_models::mandates::MandateDataType::SingleUse(i) => {
-                    api::MandateType::SingleUse(api::payments::MandateAmountData {
-                        amount: i.amount,
-                        currency: i.currency,
-                        start_date: i.start_date,
-                        end_date: i.end_date,
-                        metadata: i.metadata,
-                    })
-                }
-                hyperswitch_domain_models::mandates::MandateDataType::MultiUse(None) => {
-                    api::MandateType::MultiUse(None)
-                }
-            }),
-            update_mandate_id: d.update_mandate_id.clone(),
-        });
+        if let Some(mandate_type) = payment_data
+            .get_setup_mandate()
+            .and_then(|setup_mandate| setup_mandate.mandate_type.as_ref())
+        {
+            validate_mandate_amount_currency(mandate_type, payment_attempt.currency)?;
+        }
 
-        let order_tax_amount = payment_data
-            .get_payment_attempt()
-            .order_tax_amount
-            .or_else(|| {
-                payment_data
-                    .get_payment_intent()
-                    .tax_details
-                    .clone()
-                    .and_then(|tax| {
-                        tax.payment_method_type
-                            .map(|a| a.order_tax_amount)
-                            .or_else(|| tax.default.map(|a| a.order_tax_amount))
-                    })
-            });
+        let mandate_data = payment_data
+            .get_setup_mandate()
+            .map(map_setup_mandate_to_response);
+
+        let order_tax_amount = helpers::get_order_tax_amount(
+            payment_data.get_payment_attempt().order_tax_amount,
+            payment_data.get_payment_intent().tax_details.as_ref(),
+        );
+        let connector_error_details =
+            Some(api_models::payments::ConnectorErrorDetails::foreign_from(
+                payment_data.get_payment_attempt(),
+            ))
+            .filter(|details| !details.is_empty());
+        let amount_breakdown =
+            get_amount_breakdown(payment_data.get_payment_attempt(), order_tax_amount);
+        let decline_category = get_decline_category(payment_data.get_payment_attempt());
         let connector_mandate_id = payment_data.get_mandate_id().and_then(|mandate| {
             mandate
                 .mandate_reference_id
@@ -1146,7 +1226,8 @@ where
             status: payment_intent.status,
             amount: payment_attempt.amount,
             net_amount: payment_attempt.net_amount,
-            amount_capturable: payment_attempt.amount_capturable,
+            amount_excluding_surcharge,
+            amount_capturable,
             amount_received: payment_intent.amount_captured,
             connector: routed_through,
             client_secret: payment_intent.client_secret.map(Secret::new),
@@ -1165,8 +1246,11 @@ where
             off_session: payment_intent.off_session,
             capture_on: None,
             capture_method: payment_attempt.capture_method,
+            capture_executed,
             payment_method: payment_attempt.payment_method,
             payment_method_data: payment_method_data_response,
+            selected_card_network,
+            available_card_networks,
             payment_token: payment_attempt.payment_token,
             shipping: payment_data.get_address().get_shipping().cloned(),
             billing: payment_data.get_address().get_payment_billing().cloned(),
@@ -1207,14 +1291,29 @@ where
                 &payment_attempt.status,
                 connector_request_reference_id_config,
                 &merchant_id,
+                payment_intent.attempt_count,
+                manual_retry_config.max_attempts,
+            ),
+            suggest_alternate_payment_method: helpers::should_suggest_alternate_payment_method(
+                decline_category,
             ),
             connector_transaction_id: payment_attempt.connector_transaction_id,
+            connector_request_id: payment_attempt.connector_request_id,
+            connector_customer_id: payment_data
+                .get_connector_customer_id()
+                .map(ToOwned::to_owned),
             frm_message,
+            risk_score,
+            risk_decision,
             metadata: payment_intent.metadata,
             connector_metadata: payment_intent.connector_metadata,
+            bnpl_order_status,
             feature_metadata: payment_intent.feature_metadata,
-            reference_id: payment_attempt.connector_response_reference_id,
+            reference_id: normalize_connector_response_reference_id(
+                payment_attempt.connector_response_reference_id,
+            ),
             payment_link: payment_link_data,
+            payment_link_expired,
             profile_id: payment_intent.profile_id,
             surcharge_details,
             attempt_count: payment_intent.attempt_count,
@@ -1224,6 +1323,7 @@ where
             authorization_count: payment_intent.authorization_count,
             incremental_authorizations: incremental_authorizations_response,
             external_authentication_details,
+            cavv_present,
             external_3ds_authentication_attempted: payment_attempt
                 .external_three_ds_authentication_attempted,
             expires_on: payment_intent.session_expiry,
@@ -1238,7 +1338,10 @@ where
             frm_metadata: payment_intent.frm_metadata,
             merchant_order_reference_id: payment_intent.merchant_order_reference_id,
             order_tax_amount,
+            amount_breakdown,
             connector_mandate_id,
+            connector_error_details,
+            decline_category,
         };
 
         services::ApplicationResponse::JsonWithHeaders((payments_response, headers))
@@ -1327,6 +1430,107 @@ pub fn qr_code_next_steps_check(
     let qr_code_instructions = qr_code_steps.transpose().ok().flatten();
     Ok(qr_code_instructions)
 }
+
+/// Card networks reject statement descriptors whose descriptor and suffix together exceed
+/// [`crate::consts::MAX_STATEMENT_DESCRIPTOR_LENGTH`] characters.
+pub fn validate_statement_descriptor(
+    statement_descriptor: Option<&str>,
+    statement_descriptor_suffix: Option<&str>,
+) -> Result<(), errors::ApiErrorResponse> {
+    let descriptor_length = statement_descriptor.map_or(0, |descriptor| descriptor.chars().count())
+        + statement_descriptor_suffix.map_or(0, |suffix| suffix.chars().count());
+    fp_utils::when(
+        descriptor_length > crate::consts::MAX_STATEMENT_DESCRIPTOR_LENGTH,
+        || {
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "statement_descriptor",
+            })
+        },
+    )
+}
+
+/// External latency to be sent in the `x-hs-latency` header, latencies below the configured
+/// threshold are not reported.
+pub fn get_latency_to_report(
+    external_latency: Option<u128>,
+    latency_header_config: &LatencyHeaderConfig,
+) -> Option<u128> {
+    external_latency.filter(|latency| *latency >= u128::from(latency_header_config.threshold_in_ms))
+}
+
+/// Maps the mandate details set up with the payment to the mandate data of the payments response
+pub fn map_setup_mandate_to_response(setup_mandate: &mandates::MandateData) -> api::MandateData {
+    api::MandateData {
+        customer_acceptance: setup_mandate.customer_acceptance.clone().map(|d| {
+            api::CustomerAcceptance {
+                acceptance_type: match d.acceptance_type {
+                    hyperswitch_domain_models::mandates::AcceptanceType::Online => {
+                        api::AcceptanceType::Online
+                    }
+                    hyperswitch_domain_models::mandates::AcceptanceType::Offline => {
+                        api::AcceptanceType::Offline
+                    }
+                },
+                accepted_at: d.accepted_at,
+                online: d.online.map(|d| api::OnlineMandate {
+                    ip_address: d.ip_address,
+                    user_agent: d.user_agent,
+                }),
+            }
+        }),
+        mandate_type: setup_mandate.mandate_type.clone().map(|d| match d {
+            hyperswitch_domain_models::mandates::MandateDataType::MultiUse(Some(i)) => {
+                api::MandateType::MultiUse(Some(api::MandateAmountData {
+                    amount: i.amount,
+                    currency: i.currency,
+                    start_date: i.start_date,
+                    end_date: i.end_date,
+                    metadata: i.metadata,
+                }))
+            }
+            hyperswitch_domain_models::mandates::MandateDataType::SingleUse(i) => {
+                api::MandateType::SingleUse(api::payments::MandateAmountData {
+                    amount: i.amount,
+                    currency: i.currency,
+                    start_date: i.start_date,
+                    end_date: i.end_date,
+                    metadata: i.metadata,
+                })
+            }
+            hyperswitch_domain_models::mandates::MandateDataType::MultiUse(None) => {
+                api::MandateType::MultiUse(None)
+            }
+        }),
+        update_mandate_id: setup_mandate.update_mandate_id.clone(),
+    }
+}
+
+/// Checks that the currency of the mandate amount, when present, matches the payment currency.
+pub fn validate_mandate_amount_currency(
+    mandate_type: &mandates::MandateDataType,
+    payment_currency: Option<Currency>,
+) -> RouterResult<()> {
+    let mandate_currency = match mandate_type {
+        mandates::MandateDataType::SingleUse(mandate_amount_data)
+        | mandates::MandateDataType::MultiUse(Some(mandate_amount_data)) => {
+            Some(mandate_amount_data.currency)
+        }
+        mandates::MandateDataType::MultiUse(None) => None,
+    };
+    match (mandate_currency, payment_currency) {
+        (Some(mandate_currency), Some(payment_currency))
+            if mandate_currency != payment_currency =>
+        {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "mandate amount currency {mandate_currency} does not match the payment currency {payment_currency}"
+                ),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
 pub fn paypal_sdk_next_steps_check(
     payment_attempt: storage::PaymentAttempt,
 ) -> RouterResult<Option<api_models::payments::SdkNextActionData>> {
@@ -1369,9 +1573,104 @@ pub fn wait_screen_next_steps_check(
     Ok(display_info_with_timer_instructions)
 }
 
+/// Builds the wait screen next action, `poll_id` is the request poll id that the sdk
+/// passes to the retrieve poll status api while the wait screen is displayed
+pub fn get_wait_screen_next_action(
+    wait_screen_data: api_models::payments::WaitScreenInstructions,
+    poll_config: Option<types::PollConfig>,
+    poll_id: String,
+) -> api_models::payments::NextActionData {
+    let poll_config = poll_config.unwrap_or_default();
+    api_models::payments::NextActionData::WaitScreenInformation {
+        display_from_timestamp: wait_screen_data.display_from_timestamp,
+        display_to_timestamp: wait_screen_data.display_to_timestamp,
+        poll_config: api_models::payments::PollConfigResponse {
+            poll_id,
+            delay_in_secs: poll_config.delay_in_secs,
+            frequency: poll_config.frequency,
+        },
+    }
+}
+
+/// Reads the consent url of open banking payments, which the connector returns when the customer
+/// has to authorize the access to their bank account on a hosted page (e.g. the plaid hosted link)
+pub fn open_banking_consent_next_steps_check(
+    payment_attempt: storage::PaymentAttempt,
+) -> RouterResult<Option<api_models::payments::OpenBankingConsentInstructions>> {
+    if payment_attempt.payment_method != Some(enums::PaymentMethod::OpenBanking) {
+        return Ok(None);
+    }
+    let open_banking_consent_steps: Option<
+        Result<api_models::payments::OpenBankingConsentInstructions, _>,
+    > = payment_attempt
+        .connector_metadata
+        .map(|metadata| metadata.parse_value("OpenBankingConsentInstructions"));
+
+    let open_banking_consent_instructions = open_banking_consent_steps.transpose().ok().flatten();
+    Ok(open_banking_consent_instructions)
+}
+
+/// Assembles a page of the payments list from the intent-attempt rows fetched for it and the
+/// cursor for the next page, see [`get_payment_list_page`].
+#[cfg(feature = "v1")]
+impl
+    ForeignFrom<(
+        Vec<(storage::PaymentIntent, storage::PaymentAttempt)>,
+        Option<common_utils::id_type::PaymentId>,
+    )> for api::PaymentListResponse
+{
+    fn foreign_from(
+        (rows, next_cursor): (
+            Vec<(storage::PaymentIntent, storage::PaymentAttempt)>,
+            Option<common_utils::id_type::PaymentId>,
+        ),
+    ) -> Self {
+        let data: Vec<api::PaymentsResponse> =
+            rows.into_iter().map(ForeignFrom::foreign_from).collect();
+
+        Self {
+            size: data.len(),
+            data,
+            has_more: next_cursor.is_some(),
+            next_cursor,
+        }
+    }
+}
+
+/// Trims the intents fetched for a page of the payments list to `limit`, one more intent than the
+/// limit is expected to be fetched to tell whether there is a next page. Intents are ordered by
+/// `created_at`, so the last intent of the page is the cursor for the next page. The cursor is
+/// derived from the intents rather than the rows returned, so that intents dropped later on (e.g.
+/// for a missing attempt) do not end the pagination early.
+#[cfg(feature = "v1")]
+pub fn get_payment_list_page(
+    mut payment_intents: Vec<storage::PaymentIntent>,
+    limit: u32,
+) -> (
+    Vec<storage::PaymentIntent>,
+    Option<common_utils::id_type::PaymentId>,
+) {
+    let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+    let has_more = payment_intents.len() > limit;
+    payment_intents.truncate(limit);
+    let next_cursor = has_more
+        .then(|| {
+            payment_intents
+                .last()
+                .map(|payment_intent| payment_intent.payment_id.clone())
+        })
+        .flatten();
+    (payment_intents, next_cursor)
+}
+
 #[cfg(feature = "v1")]
 impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::PaymentsResponse {
     fn foreign_from((pi, pa): (storage::PaymentIntent, storage::PaymentAttempt)) -> Self {
+        let connector_error_details = Some(
+            api_models::payments::ConnectorErrorDetails::foreign_from(&pa),
+        )
+        .filter(|details| !details.is_empty());
+        let surcharge_details = get_request_surcharge_details(None, &pa);
         Self {
             payment_id: pi.payment_id,
             merchant_id: pi.merchant_id,
@@ -1393,8 +1692,11 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             business_sub_label: pa.business_sub_label,
             setup_future_usage: pi.setup_future_usage,
             capture_method: pa.capture_method,
+            capture_executed: None,
             authentication_type: pa.authentication_type,
             connector_transaction_id: pa.connector_transaction_id,
+            connector_request_id: pa.connector_request_id,
+            connector_customer_id: None,
             attempt_count: pi.attempt_count,
             profile_id: pi.profile_id,
             merchant_connector_id: pa.merchant_connector_id,
@@ -1407,6 +1709,8 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
                     }
                 }
             }),
+            selected_card_network: None,
+            available_card_networks: None,
             merchant_order_reference_id: pi.merchant_order_reference_id,
             customer: pi.customer_details.and_then(|customer_details|
                 match customer_details.into_inner().expose().parse_value::<CustomerData>("CustomerData"){
@@ -1416,7 +1720,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
                             name: parsed_data.name,
                             phone: parsed_data.phone,
                             email: parsed_data.email,
-                            phone_country_code:parsed_data.phone_country_code
+                            phone_country_code: normalize_phone_country_code(parsed_data.phone_country_code),
                     }),
                     Err(e) => {
                         router_env::logger::error!("Failed to parse 'CustomerDetailsResponse' from payment method data. Error: {e:?}");
@@ -1444,6 +1748,15 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             ),
             // TODO: fill in details based on requirement
             net_amount: pa.net_amount,
+            amount_excluding_surcharge: get_amount_excluding_surcharge(
+                pa.net_amount,
+                surcharge_details
+                    .as_ref()
+                    .map(|surcharge_details| surcharge_details.surcharge_amount),
+                surcharge_details
+                    .as_ref()
+                    .and_then(|surcharge_details| surcharge_details.tax_amount),
+            ),
             amount_received: None,
             refunds: None,
             disputes: None,
@@ -1462,26 +1775,32 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             statement_descriptor_suffix: None,
             next_action: None,
             cancellation_reason: None,
-            error_code: None,
-            error_message: None,
-            unified_code: None,
-            unified_message: None,
-            payment_experience: None,
+            error_code: pa.error_code,
+            error_message: pa.error_reason.or(pa.error_message),
+            unified_code: pa.unified_code,
+            unified_message: pa.unified_message,
+            payment_experience: pa.payment_experience,
             connector_label: None,
             allowed_payment_method_types: None,
             ephemeral_key: None,
             manual_retry_allowed: None,
+            suggest_alternate_payment_method: None,
             frm_message: None,
+            risk_score: None,
+            risk_decision: None,
             connector_metadata: None,
+            bnpl_order_status: None,
             feature_metadata: None,
             reference_id: None,
             payment_link: None,
-            surcharge_details: None,
+            payment_link_expired: None,
+            surcharge_details,
             merchant_decision: None,
             incremental_authorization_allowed: None,
             authorization_count: None,
             incremental_authorizations: None,
             external_authentication_details: None,
+            cavv_present: None,
             external_3ds_authentication_attempted: None,
             expires_on: None,
             fingerprint: None,
@@ -1492,7 +1811,26 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             charges: None,
             frm_metadata: None,
             order_tax_amount: None,
+            amount_breakdown: None,
             connector_mandate_id:None,
+            connector_error_details,
+            decline_category: None,
+        }
+    }
+}
+
+impl ForeignFrom<&storage::PaymentAttempt> for api_models::payments::ConnectorErrorDetails {
+    /// The connector's detailed `error_reason` takes precedence over the generic
+    /// `error_message`, matching the flat `error_message` field of the payments response
+    fn foreign_from(payment_attempt: &storage::PaymentAttempt) -> Self {
+        Self {
+            code: payment_attempt.error_code.clone(),
+            message: payment_attempt
+                .error_reason
+                .clone()
+                .or_else(|| payment_attempt.error_message.clone()),
+            unified_code: payment_attempt.unified_code.clone(),
+            unified_message: payment_attempt.unified_message.clone(),
         }
     }
 }
@@ -1541,9 +1879,18 @@ pub fn bank_transfer_next_steps_check(
 pub fn voucher_next_steps_check(
     payment_attempt: storage::PaymentAttempt,
 ) -> RouterResult<Option<api_models::payments::VoucherNextStepData>> {
-    let voucher_next_step = if let Some(diesel_models::enums::PaymentMethod::Voucher) =
-        payment_attempt.payment_method
-    {
+    // Only voucher payment method types (e.g. Boleto, Oxxo) carry voucher next steps in the connector metadata.
+    // Types like Pix follow a QR code flow and their metadata must not be parsed as voucher details.
+    let is_voucher_payment_method_type = payment_attempt
+        .payment_method_type
+        .map(diesel_models::enums::PaymentMethod::from)
+        .map_or(true, |payment_method| {
+            payment_method == diesel_models::enums::PaymentMethod::Voucher
+        });
+    let voucher_next_step = if let (Some(diesel_models::enums::PaymentMethod::Voucher), true) = (
+        payment_attempt.payment_method,
+        is_voucher_payment_method_type,
+    ) {
         let voucher_next_steps: Option<api_models::payments::VoucherNextStepData> = payment_attempt
             .connector_metadata
             .map(|metadata| {
@@ -1560,15 +1907,306 @@ pub fn voucher_next_steps_check(
     Ok(voucher_next_step)
 }
 
-pub fn change_order_details_to_new_type(
-    order_amount: i64,
-    order_details: api_models::payments::OrderDetails,
-) -> Option<Vec<api_models::payments::OrderDetailsWithAmount>> {
-    Some(vec![api_models::payments::OrderDetailsWithAmount {
-        product_name: order_details.product_name,
-        quantity: order_details.quantity,
-        amount: order_amount,
-        product_img_link: order_details.product_img_link,
+/// Parses the additional payment method data stored on the attempt, this also carries the
+/// network token details (network, last four digits and expiry) of network token payments.
+pub fn get_additional_payment_method_data(
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<api_models::payments::AdditionalPaymentData>> {
+    payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|data| match data {
+            serde_json::Value::Null => None, // This is to handle the case when the payment_method_data is null
+            _ => Some(data.parse_value("AdditionalPaymentData")),
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed to parse the AdditionalPaymentData from payment_attempt.payment_method_data",
+        )
+}
+
+/// Merges the customer details of the payments response. Each field is taken from the customer table
+/// response first, then from the customer details stored on the payment intent and lastly from the
+/// customer row. The customer id is only taken from the customer table response.
+/// When the payment intent has no customer details, or they fail to deserialize, the customer table
+/// response is returned as is.
+pub fn build_customer_details_response(
+    customer: Option<&domain::Customer>,
+    intent_customer_details: Option<serde_json::Value>,
+    table_response: Option<CustomerDetailsResponse>,
+) -> Option<CustomerDetailsResponse> {
+    let Some(intent_customer_details) = intent_customer_details
+        .and_then(|customer_details| serde_json::from_value::<CustomerData>(customer_details).ok())
+    else {
+        return table_response.map(|customer_details| CustomerDetailsResponse {
+            phone_country_code: normalize_phone_country_code(customer_details.phone_country_code),
+            ..customer_details
+        });
+    };
+
+    Some(CustomerDetailsResponse {
+        id: table_response
+            .as_ref()
+            .and_then(|customer_data| customer_data.id.clone()),
+        name: table_response
+            .as_ref()
+            .and_then(|customer_data| customer_data.name.clone())
+            .or(intent_customer_details.name)
+            .or_else(|| {
+                customer.and_then(|customer| {
+                    customer.name.as_ref().map(|name| name.clone().into_inner())
+                })
+            }),
+        email: table_response
+            .as_ref()
+            .and_then(|customer_data| customer_data.email.clone())
+            .or(intent_customer_details.email)
+            .or_else(|| customer.and_then(|customer| customer.email.clone().map(Email::from))),
+        phone: table_response
+            .as_ref()
+            .and_then(|customer_data| customer_data.phone.clone())
+            .or(intent_customer_details.phone)
+            .or_else(|| {
+                customer.and_then(|customer| {
+                    customer
+                        .phone
+                        .as_ref()
+                        .map(|phone| phone.clone().into_inner())
+                })
+            }),
+        phone_country_code: normalize_phone_country_code(
+            table_response
+                .as_ref()
+                .and_then(|customer_data| customer_data.phone_country_code.clone())
+                .or(intent_customer_details.phone_country_code)
+                .or_else(|| customer.and_then(|customer| customer.phone_country_code.clone())),
+        ),
+    })
+}
+
+/// Phone country codes are stored with and without the leading `+` depending on the source, the
+/// response always carries the `+` prefixed form.
+pub fn normalize_phone_country_code(phone_country_code: Option<String>) -> Option<String> {
+    phone_country_code
+        .map(|country_code| country_code.trim().trim_start_matches('+').to_string())
+        .filter(|country_code| !country_code.is_empty())
+        .map(|country_code| format!("+{country_code}"))
+}
+
+/// Prefers the label of the merchant connector account that processed the payment, the label
+/// computed from the business details is used only when the account could not be resolved.
+/// The label is used only when it belongs to the `merchant_connector_id` of the attempt.
+pub fn get_connector_label_from_mca(
+    attempt_merchant_connector_id: Option<&common_utils::id_type::MerchantConnectorAccountId>,
+    merchant_connector_account_label: Option<(
+        &common_utils::id_type::MerchantConnectorAccountId,
+        &str,
+    )>,
+    computed_connector_label: impl FnOnce() -> Option<String>,
+) -> Option<String> {
+    merchant_connector_account_label
+        .filter(|(merchant_connector_id, _)| {
+            attempt_merchant_connector_id == Some(*merchant_connector_id)
+        })
+        .map(|(_, label)| label.to_string())
+        .or_else(computed_connector_label)
+}
+
+/// The card network the payment was processed on and the networks the card supports.
+/// Only co-badged cards list more than one network, other cards list just their own network.
+pub fn get_card_network_details(
+    additional_payment_method_data: Option<&api_models::payments::AdditionalPaymentData>,
+) -> (Option<enums::CardNetwork>, Option<Vec<enums::CardNetwork>>) {
+    match additional_payment_method_data {
+        Some(api_models::payments::AdditionalPaymentData::Card(card_info)) => {
+            let available_card_networks = card_info
+                .co_badged_card_networks
+                .clone()
+                .or_else(|| card_info.card_network.clone().map(|network| vec![network]));
+            (card_info.card_network.clone(), available_card_networks)
+        }
+        _ => (None, None),
+    }
+}
+
+/// Amount that can still be captured on the attempt. With multiple partial captures the stored
+/// value can lag behind, so it is derived from the net amount minus the charged captures.
+pub fn get_amount_capturable(
+    payment_attempt: &storage::PaymentAttempt,
+    multiple_capture_data: Option<&MultipleCaptureData>,
+) -> MinorUnit {
+    match multiple_capture_data {
+        Some(multiple_capture_data) => {
+            let remaining_amount =
+                payment_attempt.net_amount - multiple_capture_data.get_total_charged_amount();
+            if remaining_amount > MinorUnit::new(0) {
+                remaining_amount
+            } else {
+                MinorUnit::new(0)
+            }
+        }
+        None => payment_attempt.amount_capturable,
+    }
+}
+
+/// Builds the breakdown of the amount components of an attempt, the net amount is always the sum of the components.
+/// Returns `None` when the attempt has no component other than the order amount.
+pub fn get_amount_breakdown(
+    payment_attempt: &storage::PaymentAttempt,
+    order_tax_amount: Option<MinorUnit>,
+) -> Option<api_models::payments::AmountBreakdown> {
+    let has_additional_components = payment_attempt.shipping_cost.is_some()
+        || order_tax_amount.is_some()
+        || payment_attempt.surcharge_amount.is_some()
+        || payment_attempt.tax_amount.is_some();
+    if !has_additional_components {
+        return None;
+    }
+    let amount_breakdown = api_models::payments::AmountBreakdown::new(
+        payment_attempt.amount,
+        payment_attempt.shipping_cost,
+        order_tax_amount,
+        payment_attempt.surcharge_amount,
+        payment_attempt.tax_amount,
+    );
+    if amount_breakdown.is_none() {
+        crate::logger::warn!(
+            "Amount breakdown overflowed for attempt {}",
+            payment_attempt.attempt_id
+        );
+    }
+    amount_breakdown
+}
+
+/// Whether the authentication record holds a CAVV, the value itself is never exposed.
+/// Returns `None` when the payment did not go through external authentication.
+pub fn is_cavv_present(authentication: Option<&storage::Authentication>) -> Option<bool> {
+    authentication.map(|authentication| authentication.cavv.is_some())
+}
+
+/// Connectors can return the reference id padded with whitespace, so it is trimmed and blank ids
+/// are dropped. Overlong ids are kept in full, a truncated id would no longer match the connector.
+pub fn normalize_connector_response_reference_id(
+    connector_response_reference_id: Option<String>,
+) -> Option<String> {
+    connector_response_reference_id
+        .map(|reference_id| reference_id.trim().to_string())
+        .filter(|reference_id| !reference_id.is_empty())
+        .inspect(|reference_id| {
+            if reference_id.chars().count()
+                > crate::consts::MAX_CONNECTOR_RESPONSE_REFERENCE_ID_LENGTH
+            {
+                crate::logger::warn!(
+                    "connector_response_reference_id is longer than {} characters",
+                    crate::consts::MAX_CONNECTOR_RESPONSE_REFERENCE_ID_LENGTH
+                );
+            }
+        })
+}
+
+/// Net amount of the payment without the surcharge and the tax on the surcharge, `None` when no
+/// surcharge was applied.
+pub fn get_amount_excluding_surcharge(
+    net_amount: MinorUnit,
+    surcharge_amount: Option<MinorUnit>,
+    surcharge_tax_amount: Option<MinorUnit>,
+) -> Option<MinorUnit> {
+    surcharge_amount.map(|surcharge_amount| {
+        net_amount - surcharge_amount - surcharge_tax_amount.unwrap_or_default()
+    })
+}
+
+/// Surcharge applied to the payment. The surcharge calculated for the current request takes
+/// precedence over the surcharge recorded on the payment attempt.
+pub fn get_payment_surcharge_details(
+    surcharge_details: Option<&SurchargeDetails>,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<SurchargeDetails> {
+    surcharge_details.cloned().or_else(|| {
+        payment_attempt
+            .get_surcharge_details()
+            .map(|request_surcharge_details| {
+                SurchargeDetails::from((&request_surcharge_details, payment_attempt))
+            })
+    })
+}
+
+/// Surcharge returned in the payments response, the tax is `None` when no tax was applied on the
+/// surcharge.
+pub fn get_request_surcharge_details(
+    surcharge_details: Option<&SurchargeDetails>,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<RequestSurchargeDetails> {
+    get_payment_surcharge_details(surcharge_details, payment_attempt).map(|surcharge_details| {
+        RequestSurchargeDetails {
+            surcharge_amount: surcharge_details.surcharge_amount,
+            tax_amount: Some(surcharge_details.tax_on_surcharge_amount)
+                .filter(|tax_amount| *tax_amount != MinorUnit::zero()),
+        }
+    })
+}
+
+/// Orders refunds, disputes or captures with the most recently created first, storage order is
+/// not guaranteed to be chronological.
+pub fn sort_by_created_at_desc<T>(
+    mut items: Vec<T>,
+    created_at: impl Fn(&T) -> time::PrimitiveDateTime,
+) -> Vec<T> {
+    items.sort_by_key(|item| std::cmp::Reverse(created_at(item)));
+    items
+}
+
+/// Whether the payment link of the payment has expired, `None` when the payment has no payment link.
+/// Falls back to the default session expiry when the payment intent does not carry one.
+pub fn is_payment_link_expired(
+    payment_link: Option<&api_models::payments::PaymentLinkResponse>,
+    session_expiry: Option<time::PrimitiveDateTime>,
+    created_at: time::PrimitiveDateTime,
+) -> Option<bool> {
+    payment_link.map(|_| {
+        let payment_link_expiry = session_expiry.unwrap_or_else(|| {
+            created_at.saturating_add(time::Duration::seconds(
+                crate::consts::DEFAULT_SESSION_EXPIRY,
+            ))
+        });
+        matches!(
+            crate::core::payment_link::check_payment_link_status(payment_link_expiry),
+            api_models::payments::PaymentLinkStatus::Expired
+        )
+    })
+}
+
+pub fn bnpl_order_status_check(
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<api_models::payments::BnplOrderStatus> {
+    if let Some(diesel_models::enums::PaymentMethod::PayLater) = payment_attempt.payment_method {
+        payment_attempt
+            .connector_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("order_status"))
+            .filter(|order_status| !order_status.is_null())
+            .and_then(|order_status| {
+                order_status
+                    .clone()
+                    .parse_value("BnplOrderStatus")
+                    .map_err(|error| crate::logger::warn!(bnpl_order_status_parse_error=?error))
+                    .ok()
+            })
+    } else {
+        None
+    }
+}
+
+pub fn change_order_details_to_new_type(
+    order_amount: i64,
+    order_details: api_models::payments::OrderDetails,
+) -> Option<Vec<api_models::payments::OrderDetailsWithAmount>> {
+    Some(vec![api_models::payments::OrderDetailsWithAmount {
+        product_name: order_details.product_name,
+        quantity: order_details.quantity,
+        amount: order_amount,
+        product_img_link: order_details.product_img_link,
         requires_shipping: order_details.requires_shipping,
         product_id: order_details.product_id,
         category: order_details.category,
@@ -1619,8 +2257,35 @@ where
     router_base_url: String,
     connector_name: String,
     payment_data: PaymentData<F>,
-    state: &'a SessionState,
+    connectors: &'a Connectors,
     customer_data: &'a Option<domain::Customer>,
+    merchant_connector_account: &'a helpers::MerchantConnectorAccountType,
+}
+
+/// Decides the `customer_acceptance` to be sent to the connector in an authorize call.
+/// Recurring merchant initiated charges reuse an existing mandate, so the acceptance is not re-sent,
+/// whereas the initial customer initiated mandate setup falls back to the acceptance in the mandate data.
+/// Its presence for the mandate setup is validated when the payment is confirmed.
+fn get_customer_acceptance_for_authorize(
+    customer_acceptance: Option<mandates::CustomerAcceptance>,
+    mandate_id: Option<&api_models::payments::MandateIds>,
+    setup_mandate: Option<&mandates::MandateData>,
+    setup_future_usage: Option<enums::FutureUsage>,
+) -> Option<mandates::CustomerAcceptance> {
+    let is_recurring_mandate_payment = mandate_id
+        .and_then(|mandate_ids| mandate_ids.mandate_reference_id.as_ref())
+        .is_some();
+    let is_customer_initiated_mandate_setup =
+        setup_mandate.is_some() && setup_future_usage == Some(enums::FutureUsage::OffSession);
+
+    if is_recurring_mandate_payment {
+        None
+    } else if is_customer_initiated_mandate_setup {
+        customer_acceptance
+            .or_else(|| setup_mandate.and_then(|mandate| mandate.customer_acceptance.clone()))
+    } else {
+        customer_acceptance
+    }
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -1662,24 +2327,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             .transpose()?
             .and_then(|cm| cm.noon.and_then(|noon| noon.order_category));
 
-        let order_details = additional_data
-            .payment_data
-            .payment_intent
-            .order_details
-            .map(|order_details| {
-                order_details
-                    .iter()
-                    .map(|data| {
-                        data.to_owned()
-                            .parse_value("OrderDetailsWithAmount")
-                            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                                field_name: "OrderDetailsWithAmount",
-                            })
-                            .attach_printable("Unable to parse OrderDetailsWithAmount")
-                    })
-                    .collect::<Result<Vec<_>, _>>()
-            })
-            .transpose()?;
+        let order_details = parse_order_details(&additional_data.payment_data.payment_intent)?;
 
         let complete_authorize_url = Some(helpers::create_complete_authorize_url(
             router_base_url,
@@ -1687,9 +2335,10 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             connector_name,
         ));
 
-        let webhook_url = Some(helpers::create_webhook_url(
+        let webhook_url = Some(helpers::get_webhook_url_with_merchant_connector_id(
             router_base_url,
             &attempt.merchant_id,
+            attempt.merchant_connector_id.as_ref(),
             connector_name,
         ));
         let router_return_url = Some(helpers::create_redirect_url(
@@ -1707,11 +2356,12 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 None
             }
         });
-        let amount = payment_data
-            .surcharge_details
-            .as_ref()
-            .map(|surcharge_details| surcharge_details.final_amount)
-            .unwrap_or(payment_data.amount.into());
+        let amount = get_payment_surcharge_details(
+            payment_data.surcharge_details.as_ref(),
+            &payment_data.payment_attempt,
+        )
+        .map(|surcharge_details| surcharge_details.final_amount)
+        .unwrap_or(payment_data.amount.into());
 
         let customer_name = additional_data
             .customer_data
@@ -1743,6 +2393,24 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             .merchant_order_reference_id
             .clone();
 
+        let customer_acceptance = get_customer_acceptance_for_authorize(
+            payment_data.customer_acceptance,
+            payment_data.mandate_id.as_ref(),
+            payment_data.setup_mandate.as_ref(),
+            payment_data.payment_intent.setup_future_usage,
+        );
+
+        validate_statement_descriptor(
+            payment_data
+                .payment_intent
+                .statement_descriptor_name
+                .as_deref(),
+            payment_data
+                .payment_intent
+                .statement_descriptor_suffix
+                .as_deref(),
+        )?;
+
         Ok(Self {
             payment_method_data: (payment_method_data.get_required_value("payment_method_data")?),
             setup_future_usage: payment_data.payment_intent.setup_future_usage,
@@ -1784,7 +2452,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 .as_ref()
                 .map(AuthenticationData::foreign_try_from)
                 .transpose()?,
-            customer_acceptance: payment_data.customer_acceptance,
+            customer_acceptance,
             charges,
             merchant_order_reference_id,
             integrity_object: None,
@@ -1807,6 +2475,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsSyncData
             amount,
             integrity_object: None,
             mandate_id: payment_data.mandate_id.clone(),
+            connector_customer: payment_data.connector_customer_id.clone(),
+            merchant_order_reference_id: payment_data
+                .payment_intent
+                .merchant_order_reference_id
+                .clone(),
             connector_transaction_id: match payment_data.payment_attempt.connector_transaction_id {
                 Some(connector_txn_id) => {
                     types::ResponseId::ConnectorTransactionId(connector_txn_id)
@@ -1857,6 +2530,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsSyncData
             amount,
             integrity_object: None,
             mandate_id: payment_data.mandate_id.clone(),
+            connector_customer: payment_data.connector_customer_id.clone(),
+            merchant_order_reference_id: None,
             connector_transaction_id: match payment_data.payment_attempt.connector_transaction_id {
                 Some(connector_txn_id) => {
                     types::ResponseId::ConnectorTransactionId(connector_txn_id)
@@ -1888,7 +2563,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>>
     fn try_from(additional_data: PaymentAdditionalData<'_, F>) -> Result<Self, Self::Error> {
         let payment_data = additional_data.payment_data;
         let connector = api::ConnectorData::get_connector_by_name(
-            &additional_data.state.conf.connectors,
+            additional_data.connectors,
             &additional_data.connector_name,
             api::GetToken::Connector,
             payment_data.payment_attempt.merchant_connector_id.clone(),
@@ -1924,17 +2599,46 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>>
     }
 }
 
+/// GSM decision stored on the attempt when the connector error was handled. It is only applicable
+/// to failed attempts, an attempt that later succeeded must not report the earlier connector error.
+pub fn get_decline_category(
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<api_models::gsm::GsmDecision> {
+    matches!(
+        payment_attempt.status,
+        enums::AttemptStatus::Failure | enums::AttemptStatus::AuthorizationFailed
+    )
+    .then_some(payment_attempt.decline_category.as_deref())
+    .flatten()
+    .and_then(|decision| api_models::gsm::GsmDecision::from_str(decision).ok())
+}
+
+/// Reads the connector transaction id from the attempt's connector metadata with the connector's
+/// own parser, for connectors that do not persist it in `connector_transaction_id`.
+fn connector_transaction_id_from_meta<F>(
+    parse_connector_meta: F,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Result<Option<String>, errors::ApiErrorResponse>
+where
+    F: FnOnce(
+        &Option<serde_json::Value>,
+    ) -> errors::CustomResult<Option<String>, errors::ConnectorError>,
+{
+    parse_connector_meta(&payment_attempt.connector_metadata)
+        .map_err(|_| errors::ApiErrorResponse::ResourceIdNotFound)
+}
+
 impl ConnectorTransactionId for Helcim {
     fn connector_transaction_id(
         &self,
         payment_attempt: storage::PaymentAttempt,
     ) -> Result<Option<String>, errors::ApiErrorResponse> {
-        if payment_attempt.connector_transaction_id.is_none() {
-            let metadata =
-                Self::connector_transaction_id(self, &payment_attempt.connector_metadata);
-            metadata.map_err(|_| errors::ApiErrorResponse::ResourceIdNotFound)
-        } else {
-            Ok(payment_attempt.connector_transaction_id)
+        match payment_attempt.connector_transaction_id {
+            Some(connector_transaction_id) => Ok(Some(connector_transaction_id)),
+            None => connector_transaction_id_from_meta(
+                |connector_meta| Self::connector_transaction_id(self, connector_meta),
+                &payment_attempt,
+            ),
         }
     }
 }
@@ -1944,8 +2648,10 @@ impl ConnectorTransactionId for Nexinets {
         &self,
         payment_attempt: storage::PaymentAttempt,
     ) -> Result<Option<String>, errors::ApiErrorResponse> {
-        let metadata = Self::connector_transaction_id(self, &payment_attempt.connector_metadata);
-        metadata.map_err(|_| errors::ApiErrorResponse::ResourceIdNotFound)
+        connector_transaction_id_from_meta(
+            |connector_meta| Self::connector_transaction_id(self, connector_meta),
+            &payment_attempt,
+        )
     }
 }
 
@@ -1965,7 +2671,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
     fn try_from(additional_data: PaymentAdditionalData<'_, F>) -> Result<Self, Self::Error> {
         let payment_data = additional_data.payment_data;
         let connector = api::ConnectorData::get_connector_by_name(
-            &additional_data.state.conf.connectors,
+            additional_data.connectors,
             &additional_data.connector_name,
             api::GetToken::Connector,
             payment_data.payment_attempt.merchant_connector_id.clone(),
@@ -2008,6 +2714,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
             browser_info,
             metadata: payment_data.payment_intent.metadata,
             integrity_object: None,
+            shipping_cost: payment_data.payment_intent.shipping_cost,
+            merchant_order_reference_id: payment_data.payment_intent.merchant_order_reference_id,
         })
     }
 }
@@ -2028,7 +2736,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
     fn try_from(additional_data: PaymentAdditionalData<'_, F>) -> Result<Self, Self::Error> {
         let payment_data = additional_data.payment_data;
         let connector = api::ConnectorData::get_connector_by_name(
-            &additional_data.state.conf.connectors,
+            additional_data.connectors,
             &additional_data.connector_name,
             api::GetToken::Connector,
             payment_data.payment_attempt.merchant_connector_id.clone(),
@@ -2055,6 +2763,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
             connector_meta: payment_data.payment_attempt.connector_metadata,
             browser_info,
             metadata: payment_data.payment_intent.metadata,
+            connector_customer: payment_data.connector_customer_id,
         })
     }
 }
@@ -2065,13 +2774,32 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsApproveD
     fn try_from(additional_data: PaymentAdditionalData<'_, F>) -> Result<Self, Self::Error> {
         let payment_data = additional_data.payment_data;
         let amount = MinorUnit::from(payment_data.amount);
+        let (reason, decision_id) = get_frm_approval_details(payment_data.frm_message.as_ref());
         Ok(Self {
             amount: Some(amount.get_amount_as_i64()), //need to change after we move to connector module
             currency: Some(payment_data.currency),
+            reason,
+            decision_id,
+            connector_meta: payment_data.payment_attempt.connector_metadata,
         })
     }
 }
 
+/// Reason and decision id of the fraud check, forwarded to the connector when approving a payment
+/// held for manual review.
+pub fn get_frm_approval_details(
+    frm_message: Option<&payments::FraudCheck>,
+) -> (Option<serde_json::Value>, Option<String>) {
+    frm_message
+        .map(|fraud_check| {
+            (
+                fraud_check.frm_reason.clone(),
+                fraud_check.frm_transaction_id.clone(),
+            )
+        })
+        .unwrap_or_default()
+}
+
 #[cfg(feature = "v2")]
 impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::SdkPaymentsSessionUpdateData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
@@ -2098,7 +2826,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::SdkPaymentsSessi
         let amount = payment_data.payment_intent.amount;
 
         Ok(Self {
-            net_amount: amount + order_tax_amount, //need to change after we move to connector module
+            net_amount: get_net_amount_with_overflow_check(amount, &[Some(order_tax_amount)])?, //need to change after we move to connector module
             order_tax_amount,
         })
     }
@@ -2113,6 +2841,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsRejectDa
         Ok(Self {
             amount: Some(amount.get_amount_as_i64()), //need to change after we move to connector module
             currency: Some(payment_data.currency),
+            connector_meta: payment_data.payment_attempt.connector_metadata,
         })
     }
 }
@@ -2122,24 +2851,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsSessionD
     fn try_from(additional_data: PaymentAdditionalData<'_, F>) -> Result<Self, Self::Error> {
         let payment_data = additional_data.payment_data.clone();
 
-        let order_details = additional_data
-            .payment_data
-            .payment_intent
-            .order_details
-            .map(|order_details| {
-                order_details
-                    .iter()
-                    .map(|data| {
-                        data.to_owned()
-                            .parse_value("OrderDetailsWithAmount")
-                            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                                field_name: "OrderDetailsWithAmount",
-                            })
-                            .attach_printable("Unable to parse OrderDetailsWithAmount")
-                    })
-                    .collect::<Result<Vec<_>, _>>()
-            })
-            .transpose()?;
+        let order_details = parse_order_details(&additional_data.payment_data.payment_intent)?;
         let amount = payment_data
             .surcharge_details
             .as_ref()
@@ -2150,20 +2862,135 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsSessionD
             amount: amount.get_amount_as_i64(), //need to change once we move to connector module
             minor_amount: amount,
             currency: payment_data.currency,
-            country: payment_data.address.get_payment_method_billing().and_then(
-                |billing_address| {
-                    billing_address
-                        .address
-                        .as_ref()
-                        .and_then(|address| address.country)
-                },
-            ),
+            country: get_session_country(&payment_data.address),
             order_details,
             surcharge_details: payment_data.surcharge_details,
+            wallet_merchant_data: get_wallet_merchant_data(
+                additional_data
+                    .merchant_connector_account
+                    .get_connector_wallets_details(),
+                additional_data.merchant_connector_account.get_metadata(),
+            ),
+            connector_meta: get_session_connector_meta(&payment_data.payment_intent),
         })
     }
 }
 
+/// Merchant identifiers registered with Apple Pay and Google Pay, read from the connector wallet
+/// details and the metadata of the merchant connector account respectively. Accounts created
+/// before the wallet details existed still carry the Apple Pay details in the metadata.
+pub fn get_wallet_merchant_data(
+    connector_wallets_details: Option<pii::SecretSerdeValue>,
+    connector_metadata: Option<pii::SecretSerdeValue>,
+) -> Option<types::WalletMerchantData> {
+    let apple_pay_merchant_identifier = helpers::get_applepay_metadata(connector_wallets_details)
+        .or_else(|_| helpers::get_applepay_metadata(connector_metadata.clone()))
+        .ok()
+        .and_then(|apple_pay_metadata| match apple_pay_metadata {
+            api_models::payments::ApplepaySessionTokenMetadata::ApplePayCombined(
+                api_models::payments::ApplePayCombinedMetadata::Manual {
+                    session_token_data, ..
+                },
+            )
+            | api_models::payments::ApplepaySessionTokenMetadata::ApplePay(
+                api_models::payments::ApplePayMetadata {
+                    session_token_data, ..
+                },
+            ) => Some(session_token_data.merchant_identifier),
+            api_models::payments::ApplepaySessionTokenMetadata::ApplePayCombined(
+                api_models::payments::ApplePayCombinedMetadata::Simplified { .. },
+            ) => None,
+        });
+    let google_pay_gateway_merchant_id = connector_metadata
+        .and_then(|metadata| {
+            metadata
+                .expose()
+                .parse_value::<api_models::payments::GpaySessionTokenData>("GpaySessionTokenData")
+                .ok()
+        })
+        .and_then(|google_pay_metadata| {
+            google_pay_metadata
+                .data
+                .allowed_payment_methods
+                .into_iter()
+                .find_map(|allowed_payment_method| {
+                    allowed_payment_method
+                        .tokenization_specification
+                        .parameters
+                        .gateway_merchant_id
+                })
+        });
+
+    (apple_pay_merchant_identifier.is_some() || google_pay_gateway_merchant_id.is_some()).then_some(
+        types::WalletMerchantData {
+            apple_pay_merchant_identifier,
+            google_pay_gateway_merchant_id,
+        },
+    )
+}
+
+/// Order details of the payment intent, parsed into `OrderDetailsWithAmount`
+pub fn parse_order_details(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<Vec<api_models::payments::OrderDetailsWithAmount>>> {
+    payment_intent
+        .order_details
+        .as_ref()
+        .map(|order_details| {
+            order_details
+                .iter()
+                .map(|data| {
+                    data.to_owned()
+                        .parse_value("OrderDetailsWithAmount")
+                        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                            field_name: "OrderDetailsWithAmount",
+                        })
+                        .attach_printable("Unable to parse OrderDetailsWithAmount")
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+}
+
+/// Country used for wallet session creation.
+/// The billing address country is preferred, falling back to the shipping address country.
+pub fn get_session_country(
+    payment_address: &types::PaymentAddress,
+) -> Option<common_enums::CountryAlpha2> {
+    let get_country =
+        |address: &Address| address.address.as_ref().and_then(|details| details.country);
+
+    payment_address
+        .get_payment_method_billing()
+        .and_then(get_country)
+        .or_else(|| payment_address.get_shipping().and_then(get_country))
+}
+
+/// Connector metadata of the payment intent, passed on to connectors during session creation.
+/// An explicit JSON `null` is treated as absent.
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "payment_v2")))]
+pub fn get_session_connector_meta(
+    payment_intent: &storage::PaymentIntent,
+) -> Option<serde_json::Value> {
+    payment_intent
+        .connector_metadata
+        .clone()
+        .filter(|connector_metadata| !connector_metadata.is_null())
+}
+
+/// Connector metadata of the payment intent, passed on to connectors during session creation.
+/// An explicit JSON `null` is treated as absent.
+#[cfg(all(feature = "v2", feature = "payment_v2"))]
+pub fn get_session_connector_meta(
+    payment_intent: &storage::PaymentIntent,
+) -> Option<serde_json::Value> {
+    payment_intent
+        .connector_metadata
+        .clone()
+        .map(ExposeInterface::expose)
+        .filter(|connector_metadata| !connector_metadata.is_null())
+}
+
 #[cfg(feature = "v1")]
 impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::SetupMandateRequestData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
@@ -2359,27 +3186,12 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsPreProce
         let attempt = &payment_data.payment_attempt;
         let connector_name = &additional_data.connector_name;
 
-        let order_details = payment_data
-            .payment_intent
-            .order_details
-            .map(|order_details| {
-                order_details
-                    .iter()
-                    .map(|data| {
-                        data.to_owned()
-                            .parse_value("OrderDetailsWithAmount")
-                            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                                field_name: "OrderDetailsWithAmount",
-                            })
-                            .attach_printable("Unable to parse OrderDetailsWithAmount")
-                    })
-                    .collect::<Result<Vec<_>, _>>()
-            })
-            .transpose()?;
+        let order_details = parse_order_details(&payment_data.payment_intent)?;
 
-        let webhook_url = Some(helpers::create_webhook_url(
+        let webhook_url = Some(helpers::get_webhook_url_with_merchant_connector_id(
             router_base_url,
             &attempt.merchant_id,
+            attempt.merchant_connector_id.as_ref(),
             connector_name,
         ));
         let router_return_url = Some(helpers::create_redirect_url(
@@ -2407,6 +3219,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsPreProce
             .as_ref()
             .map(|surcharge_details| surcharge_details.final_amount)
             .unwrap_or(payment_data.amount.into());
+        let (customer_id, customer_name) =
+            get_customer_id_and_name(additional_data.customer_data.as_ref());
 
         Ok(Self {
             payment_method_data: payment_method_data.map(From::from),
@@ -2428,10 +3242,44 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsPreProce
             mandate_id: payment_data.mandate_id,
             related_transaction_id: None,
             enrolled_for_3ds: true,
+            customer_id,
+            customer_name,
         })
     }
 }
 
+/// Identity of the customer for the connectors that register the customer before authorizing
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+pub fn get_customer_id_and_name(
+    customer_data: Option<&domain::Customer>,
+) -> (
+    Option<common_utils::id_type::CustomerId>,
+    Option<Secret<String>>,
+) {
+    (
+        customer_data.map(|customer| customer.customer_id.clone()),
+        customer_data
+            .and_then(|customer| customer.name.as_ref())
+            .map(|name| name.clone().into_inner()),
+    )
+}
+
+/// Identity of the customer for the connectors that register the customer before authorizing
+#[cfg(all(feature = "v2", feature = "customer_v2"))]
+pub fn get_customer_id_and_name(
+    customer_data: Option<&domain::Customer>,
+) -> (
+    Option<common_utils::id_type::CustomerId>,
+    Option<Secret<String>>,
+) {
+    (
+        customer_data.and_then(|customer| customer.merchant_reference_id.clone()),
+        customer_data
+            .and_then(|customer| customer.name.as_ref())
+            .map(|name| name.clone().into_inner()),
+    )
+}
+
 impl ForeignFrom<payments::FraudCheck> for FrmMessage {
     fn foreign_from(fraud_check: payments::FraudCheck) -> Self {
         Self {
@@ -2451,9 +3299,2357 @@ impl ForeignFrom<CustomerDetails> for router_request_types::CustomerDetails {
         Self {
             customer_id: Some(customer.id),
             name: customer.name,
-            email: customer.email,
+            email: customer.email.and_then(get_valid_customer_email),
             phone: customer.phone,
             phone_country_code: customer.phone_country_code,
         }
     }
 }
+
+/// Emails decrypted from storage are not validated again, so a malformed email is dropped here
+/// instead of being sent to the connector.
+pub fn get_valid_customer_email(email: Email) -> Option<Email> {
+    match Email::from_str(email.peek()) {
+        Ok(_) => Some(email),
+        Err(error) => {
+            crate::logger::warn!("Dropping malformed customer email: {error:?}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_payment_attempt() -> storage::PaymentAttempt {
+        storage::PaymentAttempt {
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            attempt_id: "pay_123_1".to_string(),
+            status: enums::AttemptStatus::Pending,
+            amount: MinorUnit::new(1000),
+            net_amount: MinorUnit::new(1000),
+            currency: Some(Currency::USD),
+            save_to_locker: None,
+            connector: Some("adyen".to_string()),
+            error_message: None,
+            offer_amount: None,
+            surcharge_amount: None,
+            tax_amount: None,
+            payment_method_id: None,
+            payment_method: None,
+            connector_transaction_id: None,
+            capture_method: None,
+            capture_on: None,
+            confirm: true,
+            authentication_type: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            last_synced: None,
+            cancellation_reason: None,
+            amount_to_capture: None,
+            mandate_id: None,
+            browser_info: None,
+            error_code: None,
+            payment_token: None,
+            connector_metadata: None,
+            payment_experience: None,
+            payment_method_type: None,
+            payment_method_data: None,
+            business_sub_label: None,
+            straight_through_algorithm: None,
+            preprocessing_step_id: None,
+            mandate_details: None,
+            error_reason: None,
+            multiple_capture_count: None,
+            connector_response_reference_id: None,
+            amount_capturable: MinorUnit::new(1000),
+            updated_by: enums::MerchantStorageScheme::PostgresOnly.to_string(),
+            authentication_data: None,
+            encoded_data: None,
+            merchant_connector_id: None,
+            unified_code: None,
+            unified_message: None,
+            external_three_ds_authentication_attempted: None,
+            authentication_connector: None,
+            authentication_id: None,
+            mandate_data: None,
+            payment_method_billing_address_id: None,
+            fingerprint_id: None,
+            charge_id: None,
+            client_source: None,
+            client_version: None,
+            customer_acceptance: None,
+            profile_id: common_utils::generate_profile_id_of_default_length(),
+            organization_id: common_utils::id_type::OrganizationId::default(),
+            shipping_cost: None,
+            order_tax_amount: None,
+            connector_request_id: None,
+            decline_category: None,
+        }
+    }
+
+    #[test]
+    fn test_fetch_qr_code_url_next_steps_check_with_display_to_timestamp() {
+        let payment_attempt = storage::PaymentAttempt {
+            connector_metadata: Some(serde_json::json!({
+                "qr_code_fetch_url": "https://example.com/qr",
+                "display_to_timestamp": 1735689600000_i64
+            })),
+            ..get_payment_attempt()
+        };
+        let qr_code_information = fetch_qr_code_url_next_steps_check(payment_attempt)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            qr_code_information.qr_code_fetch_url.as_str(),
+            "https://example.com/qr"
+        );
+        assert_eq!(
+            qr_code_information.display_to_timestamp,
+            Some(1735689600000)
+        );
+    }
+
+    #[test]
+    fn test_fetch_qr_code_url_next_steps_check_without_display_to_timestamp() {
+        let payment_attempt = storage::PaymentAttempt {
+            connector_metadata: Some(serde_json::json!({
+                "qr_code_fetch_url": "https://example.com/qr"
+            })),
+            ..get_payment_attempt()
+        };
+        let qr_code_information = fetch_qr_code_url_next_steps_check(payment_attempt)
+            .unwrap()
+            .unwrap();
+        assert_eq!(qr_code_information.display_to_timestamp, None);
+    }
+
+    #[test]
+    fn test_voucher_next_steps_check_for_voucher_attempt() {
+        let payment_attempt = storage::PaymentAttempt {
+            payment_method: Some(enums::PaymentMethod::Voucher),
+            payment_method_type: Some(enums::PaymentMethodType::Boleto),
+            connector_metadata: Some(serde_json::json!({
+                "reference": "23793381286000015734498000063309",
+                "download_url": "https://example.com/boleto.pdf"
+            })),
+            ..get_payment_attempt()
+        };
+        assert!(voucher_next_steps_check(payment_attempt).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_voucher_next_steps_check_preserves_boleto_expiry_and_reference() {
+        let payment_attempt = storage::PaymentAttempt {
+            payment_method: Some(enums::PaymentMethod::Voucher),
+            payment_method_type: Some(enums::PaymentMethodType::Boleto),
+            connector_metadata: Some(serde_json::json!({
+                "expires_at": 1735689600000_i64,
+                "reference": "23793381286000015734498000063309",
+                "download_url": "https://example.com/boleto.pdf"
+            })),
+            ..get_payment_attempt()
+        };
+        let voucher_details = voucher_next_steps_check(payment_attempt).unwrap().unwrap();
+        assert_eq!(voucher_details.expires_at, Some(1735689600000));
+        assert_eq!(
+            voucher_details.reference,
+            "23793381286000015734498000063309"
+        );
+    }
+
+    #[test]
+    fn test_voucher_next_steps_check_preserves_indomaret_expiry_and_reference() {
+        let payment_attempt = storage::PaymentAttempt {
+            payment_method: Some(enums::PaymentMethod::Voucher),
+            payment_method_type: Some(enums::PaymentMethodType::Indomaret),
+            connector_metadata: Some(serde_json::json!({
+                "expires_at": 1735776000000_i64,
+                "reference": "INDO-8831-2207",
+                "instructions_url": "https://example.com/indomaret"
+            })),
+            ..get_payment_attempt()
+        };
+        let voucher_details = voucher_next_steps_check(payment_attempt).unwrap().unwrap();
+        assert_eq!(voucher_details.expires_at, Some(1735776000000));
+        assert_eq!(voucher_details.reference, "INDO-8831-2207");
+    }
+
+    #[test]
+    fn test_voucher_next_steps_check_skips_non_voucher_payment_method_type() {
+        let payment_attempt = storage::PaymentAttempt {
+            payment_method: Some(enums::PaymentMethod::Voucher),
+            payment_method_type: Some(enums::PaymentMethodType::Pix),
+            connector_metadata: Some(serde_json::json!({
+                "qr_code_url": "https://example.com/qr.png"
+            })),
+            ..get_payment_attempt()
+        };
+        assert!(voucher_next_steps_check(payment_attempt).unwrap().is_none());
+    }
+
+    fn get_customer_acceptance() -> mandates::CustomerAcceptance {
+        mandates::CustomerAcceptance {
+            acceptance_type: mandates::AcceptanceType::Offline,
+            accepted_at: None,
+            online: None,
+        }
+    }
+
+    #[test]
+    fn test_customer_acceptance_is_sent_on_initial_mandate_setup() {
+        let customer_acceptance = get_customer_acceptance_for_authorize(
+            None,
+            None,
+            Some(&mandates::MandateData {
+                customer_acceptance: Some(get_customer_acceptance()),
+                ..Default::default()
+            }),
+            Some(enums::FutureUsage::OffSession),
+        );
+        assert_eq!(customer_acceptance, Some(get_customer_acceptance()));
+
+        let missing_customer_acceptance = get_customer_acceptance_for_authorize(
+            None,
+            None,
+            Some(&mandates::MandateData::default()),
+            Some(enums::FutureUsage::OffSession),
+        );
+        assert_eq!(missing_customer_acceptance, None);
+    }
+
+    #[test]
+    fn test_customer_acceptance_is_omitted_on_recurring_charge() {
+        let mandate_ids = api_models::payments::MandateIds {
+            mandate_id: Some("man_123".to_string()),
+            mandate_reference_id: Some(
+                api_models::payments::MandateReferenceId::ConnectorMandateId(
+                    api_models::payments::ConnectorMandateReferenceId {
+                        connector_mandate_id: Some("pm_123".to_string()),
+                        payment_method_id: None,
+                        update_history: None,
+                        mandate_metadata: None,
+                    },
+                ),
+            ),
+        };
+        let customer_acceptance = get_customer_acceptance_for_authorize(
+            Some(get_customer_acceptance()),
+            Some(&mandate_ids),
+            None,
+            Some(enums::FutureUsage::OffSession),
+        );
+        assert_eq!(customer_acceptance, None);
+    }
+
+    #[test]
+    fn test_connector_transaction_id_present_in_column() {
+        let payment_attempt = storage::PaymentAttempt {
+            connector_transaction_id: Some("txn_123".to_string()),
+            connector_metadata: Some(serde_json::json!({ "preauth_transaction_id": 456 })),
+            ..get_payment_attempt()
+        };
+        assert_eq!(
+            ConnectorTransactionId::connector_transaction_id(&Helcim, payment_attempt).unwrap(),
+            Some("txn_123".to_string())
+        );
+    }
+
+    #[test]
+    fn test_connector_transaction_id_present_in_meta() {
+        let payment_attempt = storage::PaymentAttempt {
+            connector_metadata: Some(serde_json::json!({ "preauth_transaction_id": 456 })),
+            ..get_payment_attempt()
+        };
+        assert_eq!(
+            ConnectorTransactionId::connector_transaction_id(&Helcim, payment_attempt).unwrap(),
+            Some("456".to_string())
+        );
+
+        let payment_attempt = storage::PaymentAttempt {
+            connector_metadata: Some(serde_json::json!({
+                "transaction_id": "txn_789",
+                "psync_flow": "DEBIT"
+            })),
+            ..get_payment_attempt()
+        };
+        assert_eq!(
+            ConnectorTransactionId::connector_transaction_id(&Nexinets, payment_attempt).unwrap(),
+            Some("txn_789".to_string())
+        );
+    }
+
+    #[test]
+    fn test_connector_transaction_id_absent() {
+        assert!(matches!(
+            ConnectorTransactionId::connector_transaction_id(&Helcim, get_payment_attempt()),
+            Err(errors::ApiErrorResponse::ResourceIdNotFound)
+        ));
+
+        let payment_attempt = storage::PaymentAttempt {
+            connector_metadata: Some(serde_json::json!({ "order_id": "order_1" })),
+            ..get_payment_attempt()
+        };
+        assert!(matches!(
+            ConnectorTransactionId::connector_transaction_id(&Helcim, payment_attempt),
+            Err(errors::ApiErrorResponse::ResourceIdNotFound)
+        ));
+
+        let payment_attempt = storage::PaymentAttempt {
+            connector_metadata: Some(serde_json::json!({
+                "order_id": "order_1",
+                "psync_flow": "PREAUTH"
+            })),
+            ..get_payment_attempt()
+        };
+        assert_eq!(
+            ConnectorTransactionId::connector_transaction_id(&Nexinets, payment_attempt).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bnpl_order_status_for_klarna_payment() {
+        let payment_attempt = storage::PaymentAttempt {
+            connector: Some("klarna".to_string()),
+            payment_method: Some(enums::PaymentMethod::PayLater),
+            payment_method_type: Some(enums::PaymentMethodType::Klarna),
+            connector_metadata: Some(serde_json::json!({
+                "capture_id": null,
+                "order_status": "PART_CAPTURED"
+            })),
+            ..get_payment_attempt()
+        };
+        assert_eq!(
+            bnpl_order_status_check(&payment_attempt),
+            Some(api_models::payments::BnplOrderStatus::PartCaptured)
+        );
+    }
+
+    #[test]
+    fn test_bnpl_order_status_for_non_bnpl_payment() {
+        let payment_attempt = storage::PaymentAttempt {
+            payment_method: Some(enums::PaymentMethod::Card),
+            connector_metadata: Some(serde_json::json!({ "order_status": "CAPTURED" })),
+            ..get_payment_attempt()
+        };
+        assert_eq!(bnpl_order_status_check(&payment_attempt), None);
+    }
+
+    #[test]
+    fn test_display_amount_on_sdk_with_surcharge_for_three_decimal_currency() {
+        let display_amount = api_models::payments::DisplayAmountOnSdk::foreign_try_from((
+            MinorUnit::new(12345),
+            Some(MinorUnit::new(1000)),
+            None,
+            Some(MinorUnit::new(250)),
+            Currency::KWD,
+        ))
+        .unwrap();
+
+        assert_eq!(display_amount.net_amount.get_amount_as_string(), "12.345");
+        assert_eq!(
+            display_amount
+                .shipping_cost
+                .map(|cost| cost.get_amount_as_string()),
+            Some("1.000".to_string())
+        );
+        assert_eq!(display_amount.order_tax_amount, None);
+        assert_eq!(
+            display_amount
+                .surcharge_amount
+                .map(|surcharge| surcharge.get_amount_as_string()),
+            Some("0.250".to_string())
+        );
+    }
+
+    fn get_request_billing_address() -> Address {
+        Address {
+            address: Some(api_models::payments::AddressDetails {
+                city: Some("Paris".to_string()),
+                line1: Some(Secret::new("1 Rue de Rivoli".to_string())),
+                first_name: Some(Secret::new("John".to_string())),
+                ..Default::default()
+            }),
+            phone: None,
+            email: None,
+        }
+    }
+
+    #[test]
+    fn test_unify_address_without_payment_method_billing() {
+        let payment_address =
+            types::PaymentAddress::new(None, Some(get_request_billing_address()), None, Some(true));
+
+        let unified_address = unify_address(&payment_address, None);
+
+        assert_eq!(
+            unified_address.get_payment_method_billing(),
+            Some(&get_request_billing_address())
+        );
+    }
+
+    #[test]
+    fn test_unify_address_fills_only_empty_fields() {
+        let payment_address =
+            types::PaymentAddress::new(None, Some(get_request_billing_address()), None, Some(true));
+        let payment_method_billing = Address {
+            address: Some(api_models::payments::AddressDetails {
+                city: Some("Berlin".to_string()),
+                country: Some(common_enums::CountryAlpha2::DE),
+                zip: Some(Secret::new("10115".to_string())),
+                ..Default::default()
+            }),
+            phone: None,
+            email: Some(Email::from_str("john@example.com").unwrap()),
+        };
+
+        let unified_address = unify_address(&payment_address, Some(payment_method_billing));
+        let billing = unified_address.get_payment_method_billing().unwrap();
+        let details = billing.address.as_ref().unwrap();
+
+        assert_eq!(details.city, Some("Paris".to_string()));
+        assert_eq!(
+            details.line1.as_ref().map(|line1| line1.peek().to_owned()),
+            Some("1 Rue de Rivoli".to_string())
+        );
+        assert_eq!(details.country, Some(common_enums::CountryAlpha2::DE));
+        assert_eq!(
+            details.zip.as_ref().map(|zip| zip.peek().to_owned()),
+            Some("10115".to_string())
+        );
+        assert_eq!(
+            billing.email.as_ref().map(|email| email.peek().to_owned()),
+            Some("john@example.com".to_string())
+        );
+        assert_eq!(
+            unified_address.get_payment_billing(),
+            Some(&get_request_billing_address())
+        );
+    }
+
+    fn get_address_with_country(country: common_enums::CountryAlpha2) -> Address {
+        Address {
+            address: Some(api_models::payments::AddressDetails {
+                country: Some(country),
+                ..Default::default()
+            }),
+            phone: None,
+            email: None,
+        }
+    }
+
+    #[test]
+    fn test_session_country_from_billing() {
+        let payment_address = types::PaymentAddress::new(
+            Some(get_address_with_country(common_enums::CountryAlpha2::DE)),
+            Some(get_address_with_country(common_enums::CountryAlpha2::US)),
+            None,
+            Some(true),
+        );
+        assert_eq!(
+            get_session_country(&payment_address),
+            Some(common_enums::CountryAlpha2::US)
+        );
+    }
+
+    #[test]
+    fn test_session_country_falls_back_to_shipping() {
+        let payment_address = types::PaymentAddress::new(
+            Some(get_address_with_country(common_enums::CountryAlpha2::DE)),
+            None,
+            None,
+            Some(true),
+        );
+        assert_eq!(
+            get_session_country(&payment_address),
+            Some(common_enums::CountryAlpha2::DE)
+        );
+    }
+
+    #[test]
+    fn test_session_country_absent() {
+        assert_eq!(get_session_country(&types::PaymentAddress::default()), None);
+    }
+
+    #[test]
+    fn test_amount_breakdown_without_additional_components() {
+        let payment_attempt = storage::PaymentAttempt {
+            amount: MinorUnit::new(1000),
+            net_amount: MinorUnit::new(1000),
+            ..get_payment_attempt()
+        };
+
+        assert_eq!(get_amount_breakdown(&payment_attempt, None), None);
+    }
+
+    #[test]
+    fn test_amount_breakdown_net_amount_matches_attempt() {
+        let payment_attempt = storage::PaymentAttempt {
+            amount: MinorUnit::new(1000),
+            shipping_cost: Some(MinorUnit::new(150)),
+            order_tax_amount: Some(MinorUnit::new(80)),
+            surcharge_amount: Some(MinorUnit::new(30)),
+            tax_amount: Some(MinorUnit::new(5)),
+            net_amount: MinorUnit::new(1265),
+            ..get_payment_attempt()
+        };
+        let amount_breakdown =
+            get_amount_breakdown(&payment_attempt, payment_attempt.order_tax_amount).unwrap();
+
+        assert_eq!(amount_breakdown.base_amount, payment_attempt.amount);
+        assert_eq!(amount_breakdown.net_amount, payment_attempt.net_amount);
+    }
+
+    #[test]
+    fn test_amount_breakdown_overflow() {
+        let payment_attempt = storage::PaymentAttempt {
+            amount: MinorUnit::new(i64::MAX),
+            shipping_cost: Some(MinorUnit::new(1)),
+            ..get_payment_attempt()
+        };
+
+        assert_eq!(get_amount_breakdown(&payment_attempt, None), None);
+    }
+
+    #[test]
+    fn test_risk_details_from_fraud_check() {
+        let frm_message = FrmMessage {
+            frm_name: "signifyd".to_string(),
+            frm_transaction_id: Some("frm_txn_123".to_string()),
+            frm_transaction_type: Some("pre_frm".to_string()),
+            frm_status: Some("legit".to_string()),
+            frm_score: Some(80),
+            frm_reason: None,
+            frm_error: None,
+        };
+        assert_eq!(frm_message.get_risk_score(), Some(80));
+        assert_eq!(frm_message.get_risk_decision(), Some("legit".to_string()));
+    }
+
+    #[test]
+    fn test_risk_details_without_fraud_check() {
+        let payment_data = PaymentData::<api::PSync> {
+            frm_message: None,
+            ..get_payment_data()
+        };
+        let payments_response = get_payments_response(payment_data);
+        assert_eq!(payments_response.frm_message, None);
+        assert_eq!(payments_response.risk_score, None);
+        assert_eq!(payments_response.risk_decision, None);
+    }
+
+    #[test]
+    fn test_net_amount_within_bounds() {
+        assert_eq!(
+            get_net_amount_with_overflow_check(
+                MinorUnit::new(i64::MAX - 10),
+                &[Some(MinorUnit::new(4)), None, Some(MinorUnit::new(6))]
+            )
+            .unwrap(),
+            MinorUnit::new(i64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_net_amount_overflow() {
+        let result = get_net_amount_with_overflow_check(
+            MinorUnit::new(i64::MAX - 10),
+            &[Some(MinorUnit::new(5)), Some(MinorUnit::new(6))],
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+    }
+
+    fn get_mandate_amount_data(currency: Currency) -> mandates::MandateAmountData {
+        mandates::MandateAmountData {
+            amount: MinorUnit::new(1000),
+            currency,
+            start_date: None,
+            end_date: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn test_mandate_amount_currency_mismatch() {
+        let mandate_type =
+            mandates::MandateDataType::SingleUse(get_mandate_amount_data(Currency::EUR));
+        assert!(matches!(
+            validate_mandate_amount_currency(&mandate_type, Some(Currency::USD))
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+
+        // The payments response is not built for a mandate in a different currency
+        let payment_data = PaymentData::<api::PSync> {
+            payment_attempt: storage::PaymentAttempt {
+                currency: Some(Currency::USD),
+                ..get_payment_attempt()
+            },
+            setup_mandate: Some(mandates::MandateData {
+                update_mandate_id: None,
+                customer_acceptance: None,
+                mandate_type: Some(mandate_type),
+            }),
+            ..get_payment_data()
+        };
+        let error = payments_to_payments_response(
+            payment_data,
+            None,
+            None,
+            services::AuthFlow::Merchant,
+            "http://localhost:8080",
+            &"PaymentStatus",
+            &ConnectorRequestReferenceIdConfig::default(),
+            &ManualRetryConfig::default(),
+            &LatencyHeaderConfig::default(),
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+    }
+
+    #[test]
+    fn test_mandate_amount_currency_match() {
+        let mandate_type =
+            mandates::MandateDataType::MultiUse(Some(get_mandate_amount_data(Currency::USD)));
+        assert!(validate_mandate_amount_currency(&mandate_type, Some(Currency::USD)).is_ok());
+        assert!(validate_mandate_amount_currency(
+            &mandates::MandateDataType::MultiUse(None),
+            Some(Currency::USD)
+        )
+        .is_ok());
+    }
+
+    fn get_customer_details_response(name: &str, email: &str) -> CustomerDetailsResponse {
+        CustomerDetailsResponse {
+            id: Some(
+                common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from("cus_123"))
+                    .unwrap(),
+            ),
+            name: Some(Secret::new(name.to_string())),
+            email: Some(Email::from_str(email).unwrap()),
+            phone: None,
+            phone_country_code: None,
+        }
+    }
+
+    fn get_payment_intent() -> storage::PaymentIntent {
+        storage::PaymentIntent {
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            status: enums::IntentStatus::Failed,
+            amount: MinorUnit::new(1000),
+            currency: Some(Currency::USD),
+            amount_captured: None,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            last_synced: None,
+            setup_future_usage: None,
+            fingerprint_id: None,
+            off_session: None,
+            client_secret: None,
+            active_attempt: hyperswitch_domain_models::RemoteStorageObject::ForeignID(
+                "pay_123_1".to_string(),
+            ),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 2,
+            payment_link_id: None,
+            profile_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            surcharge_applicable: None,
+            updated_by: enums::MerchantStorageScheme::PostgresOnly.to_string(),
+            request_incremental_authorization: None,
+            incremental_authorization_allowed: None,
+            authorization_count: None,
+            session_expiry: None,
+            request_external_three_ds_authentication: None,
+            charges: None,
+            frm_metadata: None,
+            customer_details: None,
+            billing_details: None,
+            merchant_order_reference_id: None,
+            shipping_details: None,
+            is_payment_processor_token_flow: None,
+            organization_id: common_utils::id_type::OrganizationId::default(),
+            shipping_cost: None,
+            tax_details: None,
+            skip_external_tax_calculation: None,
+        }
+    }
+
+    fn get_payment_data<F: Clone>() -> PaymentData<F> {
+        PaymentData {
+            flow: PhantomData,
+            payment_intent: get_payment_intent(),
+            payment_attempt: get_payment_attempt(),
+            currency: Currency::USD,
+            force_sync: None,
+            amount: api::Amount::from(MinorUnit::new(1000)),
+            email: None,
+            mandate_id: None,
+            mandate_connector: None,
+            setup_mandate: None,
+            customer_acceptance: None,
+            token: None,
+            token_data: None,
+            address: types::PaymentAddress::default(),
+            confirm: None,
+            payment_method_data: None,
+            payment_method_info: None,
+            refunds: vec![],
+            disputes: vec![],
+            attempts: None,
+            sessions_token: vec![],
+            card_cvc: None,
+            creds_identifier: None,
+            pm_token: None,
+            connector_customer_id: None,
+            recurring_mandate_payment_data: None,
+            ephemeral_key: None,
+            multiple_capture_data: None,
+            redirect_response: None,
+            surcharge_details: None,
+            frm_message: None,
+            payment_link_data: None,
+            incremental_authorization_details: None,
+            authorizations: vec![],
+            authentication: None,
+            recurring_details: None,
+            poll_config: None,
+            merchant_connector_account_label: None,
+            tax_data: None,
+        }
+    }
+
+    #[allow(clippy::panic)]
+    fn get_payments_response<F: Clone>(payment_data: PaymentData<F>) -> api::PaymentsResponse {
+        let response = payments_to_payments_response(
+            payment_data,
+            None,
+            None,
+            services::AuthFlow::Merchant,
+            "http://localhost:8080",
+            &"PaymentStatus",
+            &ConnectorRequestReferenceIdConfig::default(),
+            &ManualRetryConfig::default(),
+            &LatencyHeaderConfig::default(),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let services::ApplicationResponse::JsonWithHeaders((payments_response, _)) = response
+        else {
+            panic!("Expected a json payments response");
+        };
+        payments_response
+    }
+
+    fn get_merchant_connector_account(
+        metadata: Option<pii::SecretSerdeValue>,
+    ) -> helpers::MerchantConnectorAccountType {
+        helpers::MerchantConnectorAccountType::CacheVal(
+            api_models::admin::MerchantConnectorDetails {
+                connector_account_details: Secret::new(serde_json::json!({
+                    "auth_type": "HeaderKey",
+                    "api_key": "api_key"
+                })),
+                metadata,
+            },
+        )
+    }
+
+    fn get_additional_data<'a, F: Clone>(
+        connectors: &'a Connectors,
+        merchant_connector_account: &'a helpers::MerchantConnectorAccountType,
+        payment_data: PaymentData<F>,
+    ) -> PaymentAdditionalData<'a, F> {
+        PaymentAdditionalData {
+            router_base_url: "http://localhost:8080".to_string(),
+            connector_name: "adyen".to_string(),
+            payment_data,
+            connectors,
+            customer_data: &None,
+            merchant_connector_account,
+        }
+    }
+
+    #[test]
+    fn test_approve_and_reject_data_connector_meta() {
+        let connectors = Connectors::default();
+        let merchant_connector_account = get_merchant_connector_account(None);
+        let connector_metadata = serde_json::json!({ "psp_reference": "psp_123" });
+        let mut payment_data = get_payment_data::<api::Approve>();
+        payment_data.payment_attempt.connector_metadata = Some(connector_metadata.clone());
+
+        let approve_data = types::PaymentsApproveData::try_from(get_additional_data(
+            &connectors,
+            &merchant_connector_account,
+            payment_data.clone(),
+        ))
+        .unwrap();
+        assert_eq!(
+            approve_data.connector_meta,
+            Some(connector_metadata.clone())
+        );
+
+        let reject_data = types::PaymentsRejectData::try_from(get_additional_data(
+            &connectors,
+            &merchant_connector_account,
+            payment_data,
+        ))
+        .unwrap();
+        assert_eq!(reject_data.connector_meta, Some(connector_metadata));
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_sync_and_capture_data_merchant_order_reference_id() {
+        let connectors = Connectors::default();
+        let merchant_connector_account = get_merchant_connector_account(None);
+        let mut payment_data = get_payment_data::<api::PSync>();
+        payment_data.payment_intent.merchant_order_reference_id = Some("order_123".to_string());
+        payment_data.payment_attempt.connector_transaction_id = Some("txn_123".to_string());
+
+        let sync_data = types::PaymentsSyncData::try_from(get_additional_data(
+            &connectors,
+            &merchant_connector_account,
+            payment_data.clone(),
+        ))
+        .unwrap();
+        assert_eq!(
+            sync_data.merchant_order_reference_id,
+            Some("order_123".to_string())
+        );
+
+        let capture_data = types::PaymentsCaptureData::try_from(get_additional_data(
+            &connectors,
+            &merchant_connector_account,
+            payment_data,
+        ))
+        .unwrap();
+        assert_eq!(
+            capture_data.merchant_order_reference_id,
+            Some("order_123".to_string())
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_capture_data_shipping_cost() {
+        use crate::connector::utils::PaymentsCaptureRequestData;
+
+        let connectors = Connectors::default();
+        let merchant_connector_account = get_merchant_connector_account(None);
+        let mut payment_data = get_payment_data::<api::Capture>();
+        payment_data.payment_intent.shipping_cost = Some(MinorUnit::new(250));
+        payment_data.payment_attempt.connector_transaction_id = Some("txn_123".to_string());
+
+        let capture_data = types::PaymentsCaptureData::try_from(get_additional_data(
+            &connectors,
+            &merchant_connector_account,
+            payment_data.clone(),
+        ))
+        .unwrap();
+        assert_eq!(
+            capture_data.get_shipping_cost().unwrap(),
+            MinorUnit::new(250)
+        );
+
+        payment_data.payment_intent.shipping_cost = None;
+        let capture_data = types::PaymentsCaptureData::try_from(get_additional_data(
+            &connectors,
+            &merchant_connector_account,
+            payment_data,
+        ))
+        .unwrap();
+        assert!(capture_data.get_shipping_cost().is_err());
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_cancel_data_connector_customer() {
+        let connectors = Connectors::default();
+        let merchant_connector_account = get_merchant_connector_account(None);
+        let mut payment_data = get_payment_data::<api::Void>();
+        payment_data.connector_customer_id = Some("cus_123".to_string());
+        payment_data.payment_attempt.connector_transaction_id = Some("txn_123".to_string());
+
+        let cancel_data = types::PaymentsCancelData::try_from(get_additional_data(
+            &connectors,
+            &merchant_connector_account,
+            payment_data,
+        ))
+        .unwrap();
+        assert_eq!(cancel_data.connector_customer, Some("cus_123".to_string()));
+    }
+
+    #[test]
+    fn test_session_data_wallet_merchant_data_from_legacy_metadata() {
+        let connectors = Connectors::default();
+        let merchant_connector_account =
+            get_merchant_connector_account(Some(Secret::new(serde_json::json!({
+                "apple_pay": {
+                    "payment_request_data": {
+                        "supported_networks": ["visa"],
+                        "merchant_capabilities": ["supports3DS"],
+                        "label": "Store"
+                    },
+                    "session_token_data": {
+                        "certificate": "certificate",
+                        "certificate_keys": "certificate_keys",
+                        "merchant_identifier": "merchant.com.example",
+                        "display_name": "Store",
+                        "initiative": "web",
+                        "initiative_context": "example.com"
+                    }
+                }
+            }))));
+
+        let session_data = types::PaymentsSessionData::try_from(get_additional_data(
+            &connectors,
+            &merchant_connector_account,
+            get_payment_data::<api::Session>(),
+        ))
+        .unwrap();
+        assert_eq!(
+            session_data.wallet_merchant_data,
+            Some(types::WalletMerchantData {
+                apple_pay_merchant_identifier: Some("merchant.com.example".to_string()),
+                google_pay_gateway_merchant_id: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_payments_response_suggest_alternate_payment_method() {
+        let payment_data = PaymentData::<api::PSync> {
+            payment_attempt: storage::PaymentAttempt {
+                status: enums::AttemptStatus::Failure,
+                decline_category: Some("hard_decline".to_string()),
+                ..get_payment_attempt()
+            },
+            ..get_payment_data()
+        };
+        let payments_response = get_payments_response(payment_data);
+        assert_eq!(
+            payments_response.suggest_alternate_payment_method,
+            Some(true)
+        );
+
+        let payment_data = PaymentData::<api::PSync> {
+            payment_attempt: storage::PaymentAttempt {
+                status: enums::AttemptStatus::Failure,
+                decline_category: Some("retry".to_string()),
+                ..get_payment_attempt()
+            },
+            ..get_payment_data()
+        };
+        let payments_response = get_payments_response(payment_data);
+        assert_eq!(
+            payments_response.suggest_alternate_payment_method,
+            Some(false)
+        );
+
+        let payment_data = PaymentData::<api::PSync> {
+            payment_attempt: storage::PaymentAttempt {
+                status: enums::AttemptStatus::Charged,
+                decline_category: Some("hard_decline".to_string()),
+                ..get_payment_attempt()
+            },
+            ..get_payment_data()
+        };
+        let payments_response = get_payments_response(payment_data);
+        assert_eq!(payments_response.suggest_alternate_payment_method, None);
+    }
+
+    #[test]
+    fn test_payments_response_connector_customer_id() {
+        let payments_response = get_payments_response(get_payment_data::<api::PSync>());
+        assert_eq!(payments_response.connector_customer_id, None);
+
+        let payment_data = PaymentData::<api::PSync> {
+            connector_customer_id: Some("cus_1234567890".to_string()),
+            ..get_payment_data()
+        };
+        let payments_response = get_payments_response(payment_data);
+        assert_eq!(
+            payments_response.connector_customer_id,
+            Some("cus_1234567890".to_string())
+        );
+    }
+
+    #[test]
+    #[allow(clippy::panic)]
+    fn test_additional_payment_method_data_for_network_token_attempt() {
+        let payment_attempt = storage::PaymentAttempt {
+            payment_method: Some(enums::PaymentMethod::Card),
+            payment_method_data: Some(serde_json::json!({
+                "network_token": {
+                    "last4": "1111",
+                    "card_network": "Visa",
+                    "card_type": "credit",
+                    "token_exp_month": "12",
+                    "token_exp_year": "2030"
+                }
+            })),
+            ..get_payment_attempt()
+        };
+        let additional_payment_method_data =
+            get_additional_payment_method_data(&payment_attempt).unwrap();
+        let Some(api_models::payments::AdditionalPaymentData::NetworkToken {
+            details: Some(details),
+        }) = additional_payment_method_data
+        else {
+            panic!("expected network token additional payment data");
+        };
+        assert_eq!(details.last4, Some("1111".to_string()));
+        assert_eq!(details.card_network, Some(enums::CardNetwork::Visa));
+        assert_eq!(
+            details.token_exp_month.map(|month| month.expose()),
+            Some("12".to_string())
+        );
+        assert_eq!(
+            details.token_exp_year.map(|year| year.expose()),
+            Some("2030".to_string())
+        );
+
+        let card_attempt = get_payment_attempt();
+        assert!(get_additional_payment_method_data(&card_attempt)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_payments_response_from_intent_and_attempt() {
+        let payment_attempt = storage::PaymentAttempt {
+            status: enums::AttemptStatus::Failure,
+            capture_method: Some(enums::CaptureMethod::Manual),
+            payment_experience: Some(enums::PaymentExperience::RedirectToUrl),
+            surcharge_amount: Some(MinorUnit::new(50)),
+            tax_amount: Some(MinorUnit::new(5)),
+            error_code: Some("card_declined".to_string()),
+            error_message: Some("Card declined".to_string()),
+            error_reason: None,
+            unified_code: Some("UE_9000".to_string()),
+            unified_message: Some("Something went wrong".to_string()),
+            ..get_payment_attempt()
+        };
+        let payments_response =
+            api::PaymentsResponse::foreign_from((get_payment_intent(), payment_attempt));
+
+        assert_eq!(
+            payments_response.error_code,
+            Some("card_declined".to_string())
+        );
+        assert_eq!(
+            payments_response.error_message,
+            Some("Card declined".to_string())
+        );
+        assert_eq!(payments_response.unified_code, Some("UE_9000".to_string()));
+        assert_eq!(
+            payments_response.unified_message,
+            Some("Something went wrong".to_string())
+        );
+        assert_eq!(
+            payments_response.capture_method,
+            Some(enums::CaptureMethod::Manual)
+        );
+        assert_eq!(
+            payments_response.payment_experience,
+            Some(enums::PaymentExperience::RedirectToUrl)
+        );
+        assert_eq!(payments_response.attempt_count, 2);
+        let surcharge_details = payments_response.surcharge_details.unwrap();
+        assert_eq!(surcharge_details.surcharge_amount, MinorUnit::new(50));
+        assert_eq!(surcharge_details.tax_amount, Some(MinorUnit::new(5)));
+        assert!(payments_response.refunds.is_none());
+        assert!(payments_response.disputes.is_none());
+    }
+
+    #[test]
+    fn test_customer_details_response_prefers_table_response() {
+        let table_response = get_customer_details_response("John Doe", "john@example.com");
+        let intent_customer_details = serde_json::json!({
+            "name": "Jane Doe",
+            "email": "jane@example.com",
+            "phone": "9123456789",
+            "phone_country_code": "+1"
+        });
+        let customer_details = build_customer_details_response(
+            None,
+            Some(intent_customer_details),
+            Some(table_response),
+        )
+        .unwrap();
+
+        assert_eq!(
+            customer_details
+                .id
+                .map(|id| id.get_string_repr().to_string()),
+            Some("cus_123".to_string())
+        );
+        assert_eq!(
+            customer_details.name.map(|name| name.expose()),
+            Some("John Doe".to_string())
+        );
+        assert_eq!(
+            customer_details.email,
+            Some(Email::from_str("john@example.com").unwrap())
+        );
+        // Fields missing in the table response are taken from the intent
+        assert_eq!(
+            customer_details.phone.map(|phone| phone.expose()),
+            Some("9123456789".to_string())
+        );
+        assert_eq!(customer_details.phone_country_code, Some("+1".to_string()));
+    }
+
+    #[test]
+    fn test_customer_details_response_normalizes_phone_country_code() {
+        let table_response = CustomerDetailsResponse {
+            phone_country_code: Some("91".to_string()),
+            ..get_customer_details_response("John Doe", "john@example.com")
+        };
+        let intent_customer_details = serde_json::json!({ "phone_country_code": "+91" });
+
+        let from_table_response = build_customer_details_response(
+            None,
+            Some(intent_customer_details.clone()),
+            Some(table_response.clone()),
+        )
+        .unwrap();
+        let from_intent =
+            build_customer_details_response(None, Some(intent_customer_details), None).unwrap();
+        let without_intent =
+            build_customer_details_response(None, None, Some(table_response)).unwrap();
+
+        assert_eq!(
+            from_table_response.phone_country_code,
+            Some("+91".to_string())
+        );
+        assert_eq!(from_intent.phone_country_code, Some("+91".to_string()));
+        assert_eq!(without_intent.phone_country_code, Some("+91".to_string()));
+        assert_eq!(normalize_phone_country_code(Some(" ".to_string())), None);
+    }
+
+    #[test]
+    fn test_customer_details_response_from_intent_without_table_response() {
+        let intent_customer_details = serde_json::json!({
+            "name": "Jane Doe",
+            "email": "jane@example.com"
+        });
+        let customer_details =
+            build_customer_details_response(None, Some(intent_customer_details), None).unwrap();
+
+        assert!(customer_details.id.is_none());
+        assert_eq!(
+            customer_details.name.map(|name| name.expose()),
+            Some("Jane Doe".to_string())
+        );
+        assert_eq!(
+            customer_details.email,
+            Some(Email::from_str("jane@example.com").unwrap())
+        );
+        assert!(customer_details.phone.is_none());
+        assert!(customer_details.phone_country_code.is_none());
+    }
+
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    #[test]
+    fn test_customer_details_response_falls_back_to_customer_row() {
+        let customer = domain::Customer {
+            customer_id: common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from(
+                "cus_123",
+            ))
+            .unwrap(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            name: None,
+            email: None,
+            phone: Some(common_utils::crypto::Encryptable::new(
+                Secret::new("9123456789".to_string()),
+                Secret::new(vec![]),
+            )),
+            phone_country_code: Some("+91".to_string()),
+            description: None,
+            created_at: common_utils::date_time::now(),
+            metadata: None,
+            modified_at: common_utils::date_time::now(),
+            connector_customer: None,
+            address_id: None,
+            default_payment_method_id: None,
+            updated_by: None,
+            version: hyperswitch_domain_models::consts::API_VERSION,
+        };
+        let intent_customer_details = serde_json::json!({ "name": "Jane Doe" });
+        let customer_details =
+            build_customer_details_response(Some(&customer), Some(intent_customer_details), None)
+                .unwrap();
+
+        assert_eq!(
+            customer_details.name.map(|name| name.expose()),
+            Some("Jane Doe".to_string())
+        );
+        assert!(customer_details.email.is_none());
+        assert_eq!(
+            customer_details.phone.map(|phone| phone.expose()),
+            Some("9123456789".to_string())
+        );
+        assert_eq!(customer_details.phone_country_code, Some("+91".to_string()));
+    }
+
+    #[test]
+    fn test_customer_details_response_with_invalid_intent_customer_details() {
+        let table_response = get_customer_details_response("John Doe", "john@example.com");
+        let customer_details = build_customer_details_response(
+            None,
+            Some(serde_json::json!({ "name": ["not", "a", "string"] })),
+            Some(table_response),
+        )
+        .unwrap();
+
+        assert_eq!(
+            customer_details.name.map(|name| name.expose()),
+            Some("John Doe".to_string())
+        );
+        assert!(customer_details.phone.is_none());
+        assert!(
+            build_customer_details_response(None, Some(serde_json::json!("invalid")), None)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_validate_statement_descriptor() {
+        assert!(validate_statement_descriptor(Some("HYPERSWITCH STORE"), Some("#1234")).is_ok());
+        assert!(validate_statement_descriptor(None, None).is_ok());
+        assert!(matches!(
+            validate_statement_descriptor(Some("HYPERSWITCH STORE"), Some("#12345")),
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "statement_descriptor"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_get_latency_to_report() {
+        let latency_header_config = LatencyHeaderConfig {
+            threshold_in_ms: 1000,
+        };
+        assert_eq!(
+            get_latency_to_report(Some(1500), &latency_header_config),
+            Some(1500)
+        );
+        assert_eq!(
+            get_latency_to_report(Some(200), &latency_header_config),
+            None
+        );
+        assert_eq!(get_latency_to_report(None, &latency_header_config), None);
+
+        assert_eq!(
+            get_latency_to_report(Some(0), &LatencyHeaderConfig::default()),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_connector_label_from_mca_wins_over_computed_label() {
+        let merchant_connector_id =
+            common_utils::id_type::MerchantConnectorAccountId::wrap("mca_eu".to_string()).unwrap();
+        let other_merchant_connector_id =
+            common_utils::id_type::MerchantConnectorAccountId::wrap("mca_us".to_string()).unwrap();
+        let computed_connector_label = || Some("stripe_US_default".to_string());
+
+        assert_eq!(
+            get_connector_label_from_mca(
+                Some(&merchant_connector_id),
+                Some((&merchant_connector_id, "stripe_eu_account")),
+                computed_connector_label
+            ),
+            Some("stripe_eu_account".to_string())
+        );
+        // The label of an account the attempt was not processed through is not used
+        assert_eq!(
+            get_connector_label_from_mca(
+                Some(&other_merchant_connector_id),
+                Some((&merchant_connector_id, "stripe_eu_account")),
+                computed_connector_label
+            ),
+            Some("stripe_US_default".to_string())
+        );
+        assert_eq!(
+            get_connector_label_from_mca(
+                Some(&merchant_connector_id),
+                None,
+                computed_connector_label
+            ),
+            Some("stripe_US_default".to_string())
+        );
+    }
+
+    #[test]
+    fn test_card_network_details_for_co_badged_card() {
+        let additional_payment_method_data = api_models::payments::AdditionalPaymentData::Card(
+            Box::new(api_models::payments::AdditionalCardInfo {
+                card_network: Some(enums::CardNetwork::CartesBancaires),
+                co_badged_card_networks: Some(vec![
+                    enums::CardNetwork::Visa,
+                    enums::CardNetwork::CartesBancaires,
+                ]),
+                ..Default::default()
+            }),
+        );
+        let (selected_card_network, available_card_networks) =
+            get_card_network_details(Some(&additional_payment_method_data));
+        assert_eq!(
+            selected_card_network,
+            Some(enums::CardNetwork::CartesBancaires)
+        );
+        assert_eq!(
+            available_card_networks,
+            Some(vec![
+                enums::CardNetwork::Visa,
+                enums::CardNetwork::CartesBancaires
+            ])
+        );
+    }
+
+    #[test]
+    fn test_card_network_details_for_single_network_card() {
+        let additional_payment_method_data = api_models::payments::AdditionalPaymentData::Card(
+            Box::new(api_models::payments::AdditionalCardInfo {
+                card_network: Some(enums::CardNetwork::Mastercard),
+                ..Default::default()
+            }),
+        );
+        let (selected_card_network, available_card_networks) =
+            get_card_network_details(Some(&additional_payment_method_data));
+        assert_eq!(selected_card_network, Some(enums::CardNetwork::Mastercard));
+        assert_eq!(
+            available_card_networks,
+            Some(vec![enums::CardNetwork::Mastercard])
+        );
+        assert_eq!(get_card_network_details(None), (None, None));
+    }
+
+    #[test]
+    fn test_customer_details_foreign_from_validates_email() {
+        let get_customer_details = |email: Email| CustomerDetails {
+            id: common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from("cus_123"))
+                .unwrap(),
+            name: None,
+            email: Some(email),
+            phone: None,
+            phone_country_code: None,
+        };
+
+        let customer_details = router_request_types::CustomerDetails::foreign_from(
+            get_customer_details(Email::from_str("john@example.com").unwrap()),
+        );
+        assert_eq!(
+            customer_details.email,
+            Some(Email::from_str("john@example.com").unwrap())
+        );
+
+        let invalid_email = Email::from(common_utils::crypto::Encryptable::new(
+            Secret::new("not-an-email".to_string()),
+            Secret::new(vec![]),
+        ));
+        let customer_details = router_request_types::CustomerDetails::foreign_from(
+            get_customer_details(invalid_email),
+        );
+        assert_eq!(customer_details.email, None);
+    }
+
+    #[test]
+    fn test_capture_sync_response_for_cancelled_capture() {
+        let capture_update =
+            storage::CaptureUpdate::foreign_try_from(types::CaptureSyncResponse::Success {
+                resource_id: types::ResponseId::ConnectorTransactionId("cap_123".to_string()),
+                status: enums::AttemptStatus::Voided,
+                connector_response_reference_id: None,
+                amount: Some(MinorUnit::new(100)),
+            })
+            .unwrap();
+        assert!(matches!(
+            capture_update,
+            storage::CaptureUpdate::ResponseUpdate {
+                status: enums::CaptureStatus::Cancelled,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_get_connector_auth_type() {
+        let auth_type = get_connector_auth_type(
+            serde_json::json!({ "auth_type": "HeaderKey", "api_key": "sk_test" }),
+            "stripe",
+        )
+        .unwrap();
+        assert!(matches!(
+            auth_type,
+            types::ConnectorAuthType::HeaderKey { .. }
+        ));
+
+        let error =
+            get_connector_auth_type(serde_json::json!({ "auth_type": "HeaderKey" }), "stripe")
+                .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidConnectorConfiguration { .. }
+        ));
+        assert!(format!("{error:?}").contains("ConnectorAuthType of connector stripe"));
+    }
+
+    #[test]
+    fn test_get_frm_approval_details() {
+        let fraud_check = payments::FraudCheck {
+            frm_id: "frm_123".to_string(),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            attempt_id: "pay_123_1".to_string(),
+            created_at: common_utils::date_time::now(),
+            frm_name: "signifyd".to_string(),
+            frm_transaction_id: Some("decision_123".to_string()),
+            frm_transaction_type: enums::FraudCheckType::PreFrm,
+            frm_status: enums::FraudCheckStatus::ManualReview,
+            frm_score: Some(60),
+            frm_reason: Some(serde_json::json!("reviewed by merchant")),
+            frm_error: None,
+            payment_details: None,
+            metadata: None,
+            modified_at: common_utils::date_time::now(),
+            last_step: enums::FraudCheckLastStep::CheckoutOrSale,
+            payment_capture_method: None,
+        };
+
+        assert_eq!(
+            get_frm_approval_details(Some(&fraud_check)),
+            (
+                Some(serde_json::json!("reviewed by merchant")),
+                Some("decision_123".to_string())
+            )
+        );
+        assert_eq!(get_frm_approval_details(None), (None, None));
+    }
+
+    fn get_refund(refund_id: &str, created_at: time::PrimitiveDateTime) -> storage::Refund {
+        storage::Refund {
+            internal_reference_id: format!("ref_internal_{refund_id}"),
+            refund_id: refund_id.to_string(),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            connector_transaction_id: "txn_123".to_string(),
+            connector: "stripe".to_string(),
+            connector_refund_id: None,
+            external_reference_id: None,
+            refund_type: enums::RefundType::InstantRefund,
+            total_amount: MinorUnit::new(1000),
+            currency: Currency::USD,
+            refund_amount: MinorUnit::new(100),
+            refund_status: enums::RefundStatus::Success,
+            sent_to_gateway: true,
+            refund_error_message: None,
+            metadata: None,
+            refund_arn: None,
+            created_at,
+            modified_at: created_at,
+            description: None,
+            attempt_id: "pay_123_1".to_string(),
+            refund_reason: None,
+            refund_error_code: None,
+            profile_id: None,
+            updated_by: "admin".to_string(),
+            merchant_connector_id: None,
+            charges: None,
+            organization_id: common_utils::id_type::OrganizationId::default(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_connector_response_reference_id() {
+        assert_eq!(
+            normalize_connector_response_reference_id(Some("  ref_123\n".to_string())),
+            Some("ref_123".to_string())
+        );
+
+        let overlong_reference_id =
+            "a".repeat(crate::consts::MAX_CONNECTOR_RESPONSE_REFERENCE_ID_LENGTH + 10);
+        assert_eq!(
+            normalize_connector_response_reference_id(Some(overlong_reference_id.clone())),
+            Some(overlong_reference_id)
+        );
+
+        assert_eq!(
+            normalize_connector_response_reference_id(Some("   ".to_string())),
+            None
+        );
+        assert_eq!(normalize_connector_response_reference_id(None), None);
+    }
+
+    #[test]
+    fn test_get_amount_excluding_surcharge() {
+        assert_eq!(
+            get_amount_excluding_surcharge(
+                MinorUnit::new(1150),
+                Some(MinorUnit::new(100)),
+                Some(MinorUnit::new(18)),
+            ),
+            Some(MinorUnit::new(1032))
+        );
+        assert_eq!(
+            get_amount_excluding_surcharge(MinorUnit::new(1100), Some(MinorUnit::new(100)), None),
+            Some(MinorUnit::new(1000))
+        );
+        assert_eq!(
+            get_amount_excluding_surcharge(MinorUnit::new(1000), None, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_refunds_sorted_by_created_at_desc() {
+        let now = common_utils::date_time::now();
+        let refunds = vec![
+            get_refund("ref_2", now - time::Duration::minutes(5)),
+            get_refund("ref_3", now),
+            get_refund("ref_1", now - time::Duration::minutes(10)),
+        ];
+
+        let refunds_response: Vec<api_models::refunds::RefundResponse> =
+            sort_by_created_at_desc(refunds, |refund| refund.created_at)
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect();
+
+        let refund_ids: Vec<_> = refunds_response
+            .iter()
+            .map(|refund| refund.refund_id.as_str())
+            .collect();
+        assert_eq!(refund_ids, vec!["ref_3", "ref_2", "ref_1"]);
+    }
+
+    fn get_capture(capture_id: &str, amount: i64) -> storage::Capture {
+        storage::Capture {
+            capture_id: capture_id.to_string(),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            status: enums::CaptureStatus::Charged,
+            amount: MinorUnit::new(amount),
+            currency: Some(Currency::USD),
+            connector: "adyen".to_string(),
+            error_message: None,
+            error_code: None,
+            error_reason: None,
+            tax_amount: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            authorized_attempt_id: "pay_123_1".to_string(),
+            connector_capture_id: None,
+            capture_sequence: 1,
+            connector_response_reference_id: None,
+        }
+    }
+
+    #[test]
+    fn test_amount_capturable_with_multiple_captures() {
+        let payment_attempt = storage::PaymentAttempt {
+            amount_capturable: MinorUnit::new(1000),
+            ..get_payment_attempt()
+        };
+        let multiple_capture_data = MultipleCaptureData::new_for_sync(
+            vec![get_capture("cap_1", 300), get_capture("cap_2", 300)],
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            get_amount_capturable(&payment_attempt, Some(&multiple_capture_data)),
+            MinorUnit::new(400)
+        );
+        assert_eq!(
+            get_amount_capturable(&payment_attempt, None),
+            MinorUnit::new(1000)
+        );
+    }
+
+    #[test]
+    fn test_amount_capturable_after_void_failed() {
+        // The authorization is still capturable when voiding it failed
+        let payment_attempt = storage::PaymentAttempt {
+            status: enums::AttemptStatus::VoidFailed,
+            amount_capturable: MinorUnit::new(1000),
+            ..get_payment_attempt()
+        };
+        assert_eq!(
+            get_amount_capturable(&payment_attempt, None),
+            MinorUnit::new(1000)
+        );
+
+        let multiple_capture_data =
+            MultipleCaptureData::new_for_sync(vec![get_capture("cap_1", 300)], None).unwrap();
+        assert_eq!(
+            get_amount_capturable(&payment_attempt, Some(&multiple_capture_data)),
+            MinorUnit::new(700)
+        );
+    }
+
+    fn get_authentication(cavv: Option<String>) -> storage::Authentication {
+        storage::Authentication {
+            authentication_id: "authn_123".to_string(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            authentication_connector: "threedsecureio".to_string(),
+            connector_authentication_id: None,
+            authentication_data: None,
+            payment_method_id: "pm_123".to_string(),
+            authentication_type: None,
+            authentication_status: common_enums::AuthenticationStatus::Success,
+            authentication_lifecycle_status: common_enums::AuthenticationLifecycleStatus::Used,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            error_message: None,
+            error_code: None,
+            connector_metadata: None,
+            maximum_supported_version: None,
+            threeds_server_transaction_id: None,
+            cavv,
+            authentication_flow_type: None,
+            message_version: None,
+            eci: None,
+            trans_status: None,
+            acquirer_bin: None,
+            acquirer_merchant_id: None,
+            three_ds_method_data: None,
+            three_ds_method_url: None,
+            acs_url: None,
+            challenge_request: None,
+            acs_reference_number: None,
+            acs_trans_id: None,
+            acs_signed_content: None,
+            profile_id: common_utils::generate_profile_id_of_default_length(),
+            payment_id: None,
+            merchant_connector_id:
+                common_utils::generate_merchant_connector_account_id_of_default_length(),
+            ds_trans_id: None,
+            directory_server_id: None,
+            acquirer_country_code: None,
+        }
+    }
+
+    #[test]
+    fn test_cavv_present_for_authenticated_payment() {
+        let authentication = get_authentication(Some("AAABBBCCC".to_string()));
+        assert_eq!(is_cavv_present(Some(&authentication)), Some(true));
+
+        let authentication = get_authentication(None);
+        assert_eq!(is_cavv_present(Some(&authentication)), Some(false));
+    }
+
+    #[test]
+    fn test_cavv_present_for_non_3ds_payment() {
+        assert_eq!(is_cavv_present(None), None);
+    }
+
+    fn get_payment_link() -> api_models::payments::PaymentLinkResponse {
+        api_models::payments::PaymentLinkResponse {
+            link: "https://example.com/payment_link/merchant_1/pay_123".to_string(),
+            secure_link: None,
+            payment_link_id: "plink_123".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_payment_link_active() {
+        let now = common_utils::date_time::now();
+        assert_eq!(
+            is_payment_link_expired(
+                Some(&get_payment_link()),
+                Some(now.saturating_add(time::Duration::minutes(10))),
+                now
+            ),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_payment_link_expired() {
+        let now = common_utils::date_time::now();
+        assert_eq!(
+            is_payment_link_expired(
+                Some(&get_payment_link()),
+                Some(now.saturating_sub(time::Duration::minutes(10))),
+                now.saturating_sub(time::Duration::hours(1))
+            ),
+            Some(true)
+        );
+        assert_eq!(
+            is_payment_link_expired(
+                Some(&get_payment_link()),
+                None,
+                now.saturating_sub(time::Duration::hours(1))
+            ),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_payment_link_absent() {
+        let now = common_utils::date_time::now();
+        assert_eq!(is_payment_link_expired(None, Some(now), now), None);
+    }
+
+    #[test]
+    fn test_get_wait_screen_next_action_includes_poll_config() {
+        let wait_screen_data = api_models::payments::WaitScreenInstructions {
+            display_from_timestamp: 1,
+            display_to_timestamp: Some(2),
+        };
+        let next_action = get_wait_screen_next_action(
+            wait_screen_data.clone(),
+            Some(types::PollConfig {
+                delay_in_secs: 3,
+                frequency: 10,
+            }),
+            "poll_id".to_string(),
+        );
+        assert!(matches!(
+            next_action,
+            api_models::payments::NextActionData::WaitScreenInformation {
+                display_from_timestamp: 1,
+                display_to_timestamp: Some(2),
+                poll_config: api_models::payments::PollConfigResponse {
+                    ref poll_id,
+                    delay_in_secs: 3,
+                    frequency: 10,
+                },
+            } if poll_id == "poll_id"
+        ));
+
+        let default_poll_config = types::PollConfig::default();
+        let next_action =
+            get_wait_screen_next_action(wait_screen_data, None, "poll_id".to_string());
+        assert!(matches!(
+            next_action,
+            api_models::payments::NextActionData::WaitScreenInformation {
+                poll_config: api_models::payments::PollConfigResponse {
+                    delay_in_secs,
+                    frequency,
+                    ..
+                },
+                ..
+            } if delay_in_secs == default_poll_config.delay_in_secs
+                && frequency == default_poll_config.frequency
+        ));
+    }
+
+    #[test]
+    fn test_tax_update_router_data_scaffolding() {
+        let merchant_connector_account = helpers::MerchantConnectorAccountType::CacheVal(
+            api_models::admin::MerchantConnectorDetails {
+                connector_account_details: Secret::new(serde_json::json!({
+                    "auth_type": "HeaderKey",
+                    "api_key": "api_key",
+                })),
+                metadata: None,
+            },
+        );
+        let payment_attempt = get_payment_attempt();
+
+        let router_data: types::RouterData<api::CalculateTax, (), types::PaymentsResponseData> =
+            PaymentRouterDataBuilder::new(&merchant_connector_account, "taxjar")
+                .unwrap()
+                .build(
+                    &Settings::default(),
+                    &payment_attempt.merchant_id,
+                    "taxjar",
+                    &payment_attempt,
+                    (),
+                    PaymentRouterDataOverrides::default(),
+                );
+
+        assert!(matches!(
+            router_data.connector_auth_type,
+            types::ConnectorAuthType::HeaderKey { ref api_key } if api_key.peek() == "api_key"
+        ));
+        assert_eq!(
+            router_data.connector_request_reference_id,
+            payment_attempt.attempt_id
+        );
+        assert_eq!(router_data.attempt_id, payment_attempt.attempt_id);
+        assert!(router_data.connector_wallets_details.is_none());
+    }
+
+    #[test]
+    fn test_loggable_address_details_excludes_pii() {
+        let address = Address {
+            address: Some(api_models::payments::AddressDetails {
+                line1: Some(Secret::new("221B Baker Street".to_string())),
+                first_name: Some(Secret::new("Sherlock".to_string())),
+                zip: Some(Secret::new("NW16XE".to_string())),
+                country: Some(common_enums::CountryAlpha2::GB),
+                ..Default::default()
+            }),
+            phone: None,
+            email: None,
+        };
+        let payment_address =
+            types::PaymentAddress::new(Some(address.clone()), Some(address), None, None);
+
+        let loggable_address_details = get_loggable_address_details(&payment_address);
+
+        assert!(!loggable_address_details.contains("Baker"));
+        assert!(!loggable_address_details.contains("Sherlock"));
+        assert!(!loggable_address_details.contains("NW16XE"));
+        assert!(loggable_address_details.contains("GB"));
+        assert!(loggable_address_details.contains("\"NW1\""));
+    }
+
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    #[test]
+    fn test_preprocessing_customer_details_propagate() {
+        let customer = domain::Customer {
+            customer_id: common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from(
+                "cus_123",
+            ))
+            .unwrap(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            name: Some(common_utils::crypto::Encryptable::new(
+                Secret::new("Jane Doe".to_string()),
+                Secret::new(vec![]),
+            )),
+            email: None,
+            phone: None,
+            phone_country_code: None,
+            description: None,
+            created_at: common_utils::date_time::now(),
+            metadata: None,
+            modified_at: common_utils::date_time::now(),
+            connector_customer: None,
+            address_id: None,
+            default_payment_method_id: None,
+            updated_by: None,
+            version: hyperswitch_domain_models::consts::API_VERSION,
+        };
+
+        let (customer_id, customer_name) = get_customer_id_and_name(Some(&customer));
+        assert_eq!(
+            customer_id.map(|customer_id| customer_id.get_string_repr().to_owned()),
+            Some("cus_123".to_string())
+        );
+        assert_eq!(
+            customer_name.map(|name| name.expose()),
+            Some("Jane Doe".to_string())
+        );
+
+        let (customer_id, customer_name) = get_customer_id_and_name(None);
+        assert!(customer_id.is_none());
+        assert!(customer_name.is_none());
+    }
+
+    #[test]
+    fn test_open_banking_consent_next_steps_check() {
+        let mut payment_attempt = get_payment_attempt();
+        payment_attempt.payment_method = Some(enums::PaymentMethod::OpenBanking);
+        payment_attempt.connector_metadata = Some(serde_json::json!({
+            "consent_url": "https://bank.example.com/consent",
+            "expires_at": 1_700_000_000,
+        }));
+        let open_banking_consent = open_banking_consent_next_steps_check(payment_attempt.clone())
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            open_banking_consent.consent_url.as_str(),
+            "https://bank.example.com/consent"
+        );
+        assert_eq!(open_banking_consent.expires_at, Some(1_700_000_000));
+
+        // Connector metadata of other payment methods is not read as the consent
+        let mut card_payment_attempt = payment_attempt.clone();
+        card_payment_attempt.payment_method = Some(enums::PaymentMethod::Card);
+        assert!(open_banking_consent_next_steps_check(card_payment_attempt)
+            .unwrap()
+            .is_none());
+
+        let mut payment_attempt = payment_attempt.clone();
+        payment_attempt.connector_metadata = Some(serde_json::json!({ "order_id": "order_1" }));
+        assert!(
+            open_banking_consent_next_steps_check(payment_attempt.clone())
+                .unwrap()
+                .is_none()
+        );
+
+        payment_attempt.connector_metadata = None;
+        assert!(open_banking_consent_next_steps_check(payment_attempt)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_get_wallet_merchant_data() {
+        let connector_wallets_details = Secret::new(serde_json::json!({
+            "apple_pay": {
+                "payment_request_data": {
+                    "supported_networks": ["visa"],
+                    "merchant_capabilities": ["supports3DS"],
+                    "label": "Store"
+                },
+                "session_token_data": {
+                    "certificate": "certificate",
+                    "certificate_keys": "certificate_keys",
+                    "merchant_identifier": "merchant.com.example",
+                    "display_name": "Store",
+                    "initiative": "web",
+                    "initiative_context": "example.com"
+                }
+            }
+        }));
+        let connector_metadata = Secret::new(serde_json::json!({
+            "google_pay": {
+                "merchant_info": { "merchant_name": "Store" },
+                "allowed_payment_methods": [{
+                    "type": "CARD",
+                    "parameters": {
+                        "allowed_auth_methods": ["PAN_ONLY"],
+                        "allowed_card_networks": ["VISA"]
+                    },
+                    "tokenization_specification": {
+                        "type": "PAYMENT_GATEWAY",
+                        "parameters": {
+                            "gateway": "example",
+                            "gateway_merchant_id": "gateway_merchant_1"
+                        }
+                    }
+                }]
+            }
+        }));
+
+        assert_eq!(
+            get_wallet_merchant_data(Some(connector_wallets_details), Some(connector_metadata)),
+            Some(types::WalletMerchantData {
+                apple_pay_merchant_identifier: Some("merchant.com.example".to_string()),
+                google_pay_gateway_merchant_id: Some("gateway_merchant_1".to_string()),
+            })
+        );
+        assert_eq!(get_wallet_merchant_data(None, None), None);
+    }
+
+    #[test]
+    fn test_connector_error_details_prefers_error_reason() {
+        let payment_attempt = storage::PaymentAttempt {
+            error_code: Some("card_declined".to_string()),
+            error_message: Some("Card declined".to_string()),
+            error_reason: Some("Insufficient funds in the account".to_string()),
+            unified_code: Some("UE_9000".to_string()),
+            unified_message: Some("Something went wrong".to_string()),
+            ..get_payment_attempt()
+        };
+        let error_details =
+            api_models::payments::ConnectorErrorDetails::foreign_from(&payment_attempt);
+
+        assert_eq!(
+            error_details.message,
+            Some("Insufficient funds in the account".to_string())
+        );
+        assert_eq!(error_details.code, Some("card_declined".to_string()));
+
+        let payment_attempt = storage::PaymentAttempt {
+            error_reason: None,
+            ..payment_attempt
+        };
+        let error_details =
+            api_models::payments::ConnectorErrorDetails::foreign_from(&payment_attempt);
+        assert_eq!(error_details.message, Some("Card declined".to_string()));
+
+        let payments_response =
+            api::PaymentsResponse::foreign_from((get_payment_intent(), get_payment_attempt()));
+        assert_eq!(payments_response.connector_error_details, None);
+    }
+
+    #[test]
+    fn test_get_decline_category() {
+        let payment_attempt = storage::PaymentAttempt {
+            status: enums::AttemptStatus::Failure,
+            decline_category: Some("retry".to_string()),
+            ..get_payment_attempt()
+        };
+        assert_eq!(
+            get_decline_category(&payment_attempt),
+            Some(api_models::gsm::GsmDecision::Retry)
+        );
+
+        let payment_attempt = storage::PaymentAttempt {
+            status: enums::AttemptStatus::Charged,
+            ..payment_attempt
+        };
+        assert_eq!(get_decline_category(&payment_attempt), None);
+
+        let payment_attempt = storage::PaymentAttempt {
+            status: enums::AttemptStatus::Failure,
+            decline_category: None,
+            ..payment_attempt
+        };
+        assert_eq!(get_decline_category(&payment_attempt), None);
+    }
+
+    #[test]
+    fn test_map_setup_mandate_to_response() {
+        let mandate_amount_data = mandates::MandateAmountData {
+            amount: MinorUnit::new(1000),
+            currency: enums::Currency::USD,
+            start_date: None,
+            end_date: None,
+            metadata: None,
+        };
+        let api_mandate_amount_data = api::MandateAmountData {
+            amount: MinorUnit::new(1000),
+            currency: enums::Currency::USD,
+            start_date: None,
+            end_date: None,
+            metadata: None,
+        };
+
+        let setup_mandate = mandates::MandateData {
+            update_mandate_id: Some("man_123".to_string()),
+            customer_acceptance: Some(mandates::CustomerAcceptance {
+                acceptance_type: mandates::AcceptanceType::Offline,
+                accepted_at: None,
+                online: None,
+            }),
+            mandate_type: Some(mandates::MandateDataType::SingleUse(
+                mandate_amount_data.clone(),
+            )),
+        };
+        let mandate_data = map_setup_mandate_to_response(&setup_mandate);
+        assert_eq!(
+            mandate_data.mandate_type,
+            Some(api::MandateType::SingleUse(api_mandate_amount_data.clone()))
+        );
+        assert_eq!(mandate_data.update_mandate_id, Some("man_123".to_string()));
+        assert_eq!(
+            mandate_data
+                .customer_acceptance
+                .map(|customer_acceptance| customer_acceptance.acceptance_type),
+            Some(api::AcceptanceType::Offline)
+        );
+
+        let setup_mandate = mandates::MandateData {
+            mandate_type: Some(mandates::MandateDataType::MultiUse(Some(
+                mandate_amount_data,
+            ))),
+            ..Default::default()
+        };
+        assert_eq!(
+            map_setup_mandate_to_response(&setup_mandate).mandate_type,
+            Some(api::MandateType::MultiUse(Some(api_mandate_amount_data)))
+        );
+
+        let setup_mandate = mandates::MandateData {
+            mandate_type: Some(mandates::MandateDataType::MultiUse(None)),
+            ..Default::default()
+        };
+        assert_eq!(
+            map_setup_mandate_to_response(&setup_mandate).mandate_type,
+            Some(api::MandateType::MultiUse(None))
+        );
+    }
+
+    #[test]
+    fn test_qr_code_information_with_image_and_url() {
+        let payment_attempt = storage::PaymentAttempt {
+            connector_metadata: Some(serde_json::json!({
+                "image_data_url": "data:image/png;base64,iVBORw0KGgo=",
+                "qr_code_url": "https://example.com/qr/pay_123",
+                "display_to_timestamp": 1700000000000_i64
+            })),
+            ..get_payment_attempt()
+        };
+        let qr_code_information = qr_code_next_steps_check(payment_attempt).unwrap().unwrap();
+
+        assert_eq!(
+            api_models::payments::NextActionData::foreign_from(qr_code_information),
+            api_models::payments::NextActionData::QrCodeInformation {
+                image_data_url: Some(
+                    url::Url::parse("data:image/png;base64,iVBORw0KGgo=").unwrap()
+                ),
+                qr_code_url: Some(url::Url::parse("https://example.com/qr/pay_123").unwrap()),
+                display_to_timestamp: Some(1700000000000),
+            }
+        );
+    }
+
+    #[test]
+    fn test_get_session_connector_meta() {
+        let connector_metadata = serde_json::json!({
+            "noon": {
+                "order_category": "pay"
+            }
+        });
+        let payment_intent = storage::PaymentIntent {
+            connector_metadata: Some(connector_metadata.clone()),
+            ..get_payment_intent()
+        };
+        assert_eq!(
+            get_session_connector_meta(&payment_intent),
+            Some(connector_metadata)
+        );
+
+        let payment_intent = storage::PaymentIntent {
+            connector_metadata: None,
+            ..get_payment_intent()
+        };
+        assert_eq!(get_session_connector_meta(&payment_intent), None);
+
+        let payment_intent = storage::PaymentIntent {
+            connector_metadata: Some(serde_json::Value::Null),
+            ..get_payment_intent()
+        };
+        assert_eq!(get_session_connector_meta(&payment_intent), None);
+    }
+
+    #[test]
+    fn test_payment_list_response_full_page() {
+        // One intent more than the limit is fetched when there is a next page
+        let payment_intents: Vec<_> = ["pay_1", "pay_2", "pay_3"]
+            .into_iter()
+            .map(|payment_id| storage::PaymentIntent {
+                payment_id: common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Borrowed(
+                    payment_id,
+                ))
+                .unwrap(),
+                ..get_payment_intent()
+            })
+            .collect();
+        let expected_cursor = payment_intents
+            .get(1)
+            .map(|payment_intent| payment_intent.payment_id.clone());
+        let (payment_intents, next_cursor) = get_payment_list_page(payment_intents, 2);
+        assert_eq!(payment_intents.len(), 2);
+        assert_eq!(next_cursor, expected_cursor);
+
+        let rows: Vec<_> = payment_intents
+            .into_iter()
+            .map(|payment_intent| {
+                let payment_attempt = storage::PaymentAttempt {
+                    payment_id: payment_intent.payment_id.clone(),
+                    ..get_payment_attempt()
+                };
+                (payment_intent, payment_attempt)
+            })
+            .collect();
+        let response = api::PaymentListResponse::foreign_from((rows, next_cursor));
+        assert_eq!(response.size, 2);
+        assert!(response.has_more);
+        assert_eq!(response.next_cursor, expected_cursor);
+    }
+
+    #[test]
+    fn test_payment_list_response_with_dropped_row() {
+        let payment_intents: Vec<_> = ["pay_1", "pay_2", "pay_3"]
+            .into_iter()
+            .map(|payment_id| storage::PaymentIntent {
+                payment_id: common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Borrowed(
+                    payment_id,
+                ))
+                .unwrap(),
+                ..get_payment_intent()
+            })
+            .collect();
+        let (payment_intents, next_cursor) = get_payment_list_page(payment_intents, 2);
+
+        // The attempt of the last intent is missing, so only the first row is returned
+        let rows: Vec<_> = payment_intents
+            .iter()
+            .take(1)
+            .cloned()
+            .map(|payment_intent| (payment_intent, get_payment_attempt()))
+            .collect();
+        let response = api::PaymentListResponse::foreign_from((rows, next_cursor));
+        assert_eq!(response.size, 1);
+        assert!(response.has_more);
+        assert_eq!(
+            response.next_cursor,
+            payment_intents
+                .last()
+                .map(|payment_intent| payment_intent.payment_id.clone())
+        );
+    }
+
+    #[test]
+    fn test_payment_list_response_partial_page() {
+        let payment_intents = vec![get_payment_intent()];
+        let (_, next_cursor) = get_payment_list_page(payment_intents, 10);
+        assert_eq!(next_cursor, None);
+
+        let rows = vec![(get_payment_intent(), get_payment_attempt())];
+        let response = api::PaymentListResponse::foreign_from((rows, next_cursor));
+        assert_eq!(response.size, 1);
+        assert!(!response.has_more);
+        assert_eq!(response.next_cursor, None);
+    }
+
+    #[test]
+    fn test_payment_list_page_exactly_full() {
+        // A page filled exactly up to the limit has no next page
+        let payment_intents = vec![get_payment_intent(), get_payment_intent()];
+        let (payment_intents, next_cursor) = get_payment_list_page(payment_intents, 2);
+        assert_eq!(payment_intents.len(), 2);
+        assert_eq!(next_cursor, None);
+    }
+
+    #[test]
+    fn test_get_payment_surcharge_details() {
+        let payment_attempt = storage::PaymentAttempt {
+            amount: MinorUnit::new(1000),
+            surcharge_amount: Some(MinorUnit::new(50)),
+            tax_amount: Some(MinorUnit::new(5)),
+            ..get_payment_attempt()
+        };
+
+        // The authorize amount and the surcharge in the response are derived from the surcharge
+        // recorded on the attempt when no surcharge was calculated for the request
+        let surcharge_details = get_payment_surcharge_details(None, &payment_attempt).unwrap();
+        assert_eq!(surcharge_details.surcharge_amount, MinorUnit::new(50));
+        assert_eq!(surcharge_details.final_amount, MinorUnit::new(1055));
+
+        let calculated_surcharge_details = SurchargeDetails::from((
+            &RequestSurchargeDetails {
+                surcharge_amount: MinorUnit::new(80),
+                tax_amount: None,
+            },
+            &payment_attempt,
+        ));
+        let surcharge_details =
+            get_payment_surcharge_details(Some(&calculated_surcharge_details), &payment_attempt)
+                .unwrap();
+        assert_eq!(surcharge_details.surcharge_amount, MinorUnit::new(80));
+        assert_eq!(surcharge_details.final_amount, MinorUnit::new(1080));
+
+        let payment_attempt = storage::PaymentAttempt {
+            surcharge_amount: None,
+            ..payment_attempt
+        };
+        assert!(get_payment_surcharge_details(None, &payment_attempt).is_none());
+    }
+
+    #[test]
+    fn test_payments_response_surcharge_details() {
+        let mut payment_data = get_payment_data::<api::PSync>();
+        payment_data.surcharge_details = Some(SurchargeDetails::from((
+            &RequestSurchargeDetails {
+                surcharge_amount: MinorUnit::new(80),
+                tax_amount: Some(MinorUnit::new(8)),
+            },
+            &payment_data.payment_attempt,
+        )));
+
+        let response = get_payments_response(payment_data);
+        assert_eq!(
+            response.surcharge_details,
+            Some(RequestSurchargeDetails {
+                surcharge_amount: MinorUnit::new(80),
+                tax_amount: Some(MinorUnit::new(8)),
+            })
+        );
+
+        // No tax is returned when the surcharge was applied without a tax
+        let payment_attempt = storage::PaymentAttempt {
+            surcharge_amount: Some(MinorUnit::new(80)),
+            tax_amount: None,
+            ..get_payment_attempt()
+        };
+        let response = api::PaymentsResponse::foreign_from((get_payment_intent(), payment_attempt));
+        assert_eq!(
+            response.surcharge_details,
+            Some(RequestSurchargeDetails {
+                surcharge_amount: MinorUnit::new(80),
+                tax_amount: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_payments_response_timestamps_serialize_as_utc() {
+        let date_time = time::macros::datetime!(2024-01-15 10:30:00);
+        let payment_intent = storage::PaymentIntent {
+            created_at: date_time,
+            session_expiry: Some(date_time),
+            ..get_payment_intent()
+        };
+        let payments_response = api::PaymentsResponse {
+            expires_on: payment_intent.session_expiry,
+            updated: Some(date_time),
+            ..api::PaymentsResponse::foreign_from((payment_intent, get_payment_attempt()))
+        };
+
+        let serialized = serde_json::to_value(&payments_response).unwrap();
+        for field in ["created", "expires_on", "updated"] {
+            let value = serialized[field].as_str().unwrap();
+            assert_eq!(value, "2024-01-15T10:30:00.000Z", "{field}");
+        }
+    }
+
+    #[test]
+    fn test_parse_order_details() {
+        let order_detail = serde_json::json!({
+            "product_name": "iPhone 13",
+            "quantity": 1,
+            "amount": 6540
+        });
+        let payment_intent = storage::PaymentIntent {
+            order_details: Some(vec![Secret::new(order_detail)]),
+            ..get_payment_intent()
+        };
+        let order_details = parse_order_details(&payment_intent).unwrap().unwrap();
+        assert_eq!(order_details.len(), 1);
+        assert_eq!(order_details[0].product_name, "iPhone 13");
+        assert_eq!(order_details[0].amount, 6540);
+
+        let payment_intent = storage::PaymentIntent {
+            order_details: None,
+            ..get_payment_intent()
+        };
+        assert!(parse_order_details(&payment_intent).unwrap().is_none());
+
+        // Missing quantity and amount
+        let malformed_order_detail = serde_json::json!({ "product_name": "iPhone 13" });
+        let payment_intent = storage::PaymentIntent {
+            order_details: Some(vec![Secret::new(malformed_order_detail)]),
+            ..get_payment_intent()
+        };
+        let error = parse_order_details(&payment_intent).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "OrderDetailsWithAmount"
+            }
+        ));
+    }
+}
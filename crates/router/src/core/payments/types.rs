@@ -91,7 +91,10 @@ impl MultipleCaptureData {
                         storage_enums::CaptureStatus::Charged
                         | storage_enums::CaptureStatus::Pending => capture.1.amount,
                         storage_enums::CaptureStatus::Started
-                        | storage_enums::CaptureStatus::Failed => common_types::MinorUnit::new(0),
+                        | storage_enums::CaptureStatus::Failed
+                        | storage_enums::CaptureStatus::Cancelled => {
+                            common_types::MinorUnit::new(0)
+                        }
                     }
             })
     }
@@ -104,7 +107,10 @@ impl MultipleCaptureData {
                         storage_enums::CaptureStatus::Charged => capture.1.amount,
                         storage_enums::CaptureStatus::Pending
                         | storage_enums::CaptureStatus::Started
-                        | storage_enums::CaptureStatus::Failed => common_types::MinorUnit::new(0),
+                        | storage_enums::CaptureStatus::Failed
+                        | storage_enums::CaptureStatus::Cancelled => {
+                            common_types::MinorUnit::new(0)
+                        }
                     }
             })
     }
@@ -119,6 +125,7 @@ impl MultipleCaptureData {
         hash_map.insert(storage_enums::CaptureStatus::Pending, 0);
         hash_map.insert(storage_enums::CaptureStatus::Started, 0);
         hash_map.insert(storage_enums::CaptureStatus::Failed, 0);
+        hash_map.insert(storage_enums::CaptureStatus::Cancelled, 0);
         self.all_captures
             .iter()
             .fold(hash_map, |mut accumulator, capture| {
@@ -80,7 +80,9 @@ pub async fn do_gsm_multiple_connector_actions(
                     ),
                 }))?
             }
-            api_models::gsm::GsmDecision::DoDefault => break,
+            api_models::gsm::GsmDecision::DoDefault | api_models::gsm::GsmDecision::HardDecline => {
+                break
+            }
         }
     }
     Ok(())
@@ -144,7 +146,9 @@ pub async fn do_gsm_single_connector_actions(
                     ),
                 }))?
             }
-            api_models::gsm::GsmDecision::DoDefault => break,
+            api_models::gsm::GsmDecision::DoDefault | api_models::gsm::GsmDecision::HardDecline => {
+                break
+            }
         }
     }
     Ok(())
@@ -31,7 +31,7 @@ use super::payouts::PayoutData;
 #[cfg(feature = "payouts")]
 use crate::core::payments;
 use crate::{
-    configs::Settings,
+    configs::{settings::ConnectorRequestReferenceIdScope, Settings},
     consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
@@ -209,6 +209,7 @@ pub async fn construct_payout_router_data<'a, F>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -385,6 +386,7 @@ pub async fn construct_refund_router_data<'a, F>(
         connector_api_version,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: Some(refund.refund_id.clone()),
@@ -545,6 +547,125 @@ mod tests {
         ];
         assert_eq!(filtered_list, expected_result);
     }
+
+    #[cfg(feature = "v1")]
+    fn get_payment_attempt(
+        attempt_id: &str,
+    ) -> hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt {
+        hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt {
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            attempt_id: attempt_id.to_string(),
+            status: common_enums::AttemptStatus::Failure,
+            amount: common_utils::types::MinorUnit::new(1000),
+            net_amount: common_utils::types::MinorUnit::new(1000),
+            currency: Some(common_enums::Currency::USD),
+            save_to_locker: None,
+            connector: Some("adyen".to_string()),
+            error_message: None,
+            offer_amount: None,
+            surcharge_amount: None,
+            tax_amount: None,
+            payment_method_id: None,
+            payment_method: None,
+            connector_transaction_id: None,
+            capture_method: None,
+            capture_on: None,
+            confirm: true,
+            authentication_type: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            last_synced: None,
+            cancellation_reason: None,
+            amount_to_capture: None,
+            mandate_id: None,
+            browser_info: None,
+            error_code: None,
+            payment_token: None,
+            connector_metadata: None,
+            payment_experience: None,
+            payment_method_type: None,
+            payment_method_data: None,
+            business_sub_label: None,
+            straight_through_algorithm: None,
+            preprocessing_step_id: None,
+            mandate_details: None,
+            error_reason: None,
+            multiple_capture_count: None,
+            connector_response_reference_id: None,
+            amount_capturable: common_utils::types::MinorUnit::new(1000),
+            updated_by: common_enums::MerchantStorageScheme::PostgresOnly.to_string(),
+            authentication_data: None,
+            encoded_data: None,
+            merchant_connector_id: None,
+            unified_code: None,
+            unified_message: None,
+            external_three_ds_authentication_attempted: None,
+            authentication_connector: None,
+            authentication_id: None,
+            mandate_data: None,
+            payment_method_billing_address_id: None,
+            fingerprint_id: None,
+            charge_id: None,
+            client_source: None,
+            client_version: None,
+            customer_acceptance: None,
+            profile_id: common_utils::generate_profile_id_of_default_length(),
+            organization_id: common_utils::id_type::OrganizationId::default(),
+            shipping_cost: None,
+            order_tax_amount: None,
+            connector_request_id: None,
+            decline_category: None,
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_connector_request_reference_id_scope_across_retries() {
+        let first_attempt = get_payment_attempt("pay_123_1");
+        let retried_attempt = get_payment_attempt("pay_123_2");
+        let merchant_id = first_attempt.merchant_id.clone();
+
+        let mut conf = Settings::default();
+        conf.connector_request_reference_id_config.default_scope =
+            ConnectorRequestReferenceIdScope::Payment;
+        let first_reference_id =
+            get_connector_request_reference_id(&conf, &merchant_id, &first_attempt);
+        let retried_reference_id =
+            get_connector_request_reference_id(&conf, &merchant_id, &retried_attempt);
+        assert_eq!(
+            first_reference_id,
+            first_attempt.payment_id.get_string_repr()
+        );
+        assert_eq!(first_reference_id, retried_reference_id);
+        // The default scope does not change the attempt id generation or the manual retries
+        assert!(!is_merchant_enabled_for_payment_id_as_connector_request_id(
+            &conf,
+            &merchant_id
+        ));
+
+        conf.connector_request_reference_id_config.default_scope =
+            ConnectorRequestReferenceIdScope::Attempt;
+        let first_reference_id =
+            get_connector_request_reference_id(&conf, &merchant_id, &first_attempt);
+        let retried_reference_id =
+            get_connector_request_reference_id(&conf, &merchant_id, &retried_attempt);
+        assert_eq!(first_reference_id, first_attempt.attempt_id);
+        assert_eq!(retried_reference_id, retried_attempt.attempt_id);
+
+        // Merchants configured to send the payment id are payment scoped regardless of the default
+        conf.connector_request_reference_id_config
+            .merchant_ids_send_payment_id_as_connector_request_id
+            .insert(merchant_id.clone());
+        assert_eq!(
+            get_connector_request_reference_id(&conf, &merchant_id, &retried_attempt),
+            retried_attempt.payment_id.get_string_repr()
+        );
+        assert!(is_merchant_enabled_for_payment_id_as_connector_request_id(
+            &conf,
+            &merchant_id
+        ));
+    }
 }
 
 // Dispute Stage can move linearly from PreDispute -> Dispute -> PreArbitration
@@ -695,6 +816,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         dispute_id: Some(dispute.dispute_id.clone()),
@@ -790,6 +912,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -891,6 +1014,7 @@ pub async fn construct_upload_file_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -1010,6 +1134,7 @@ pub async fn construct_payments_dynamic_tax_calculation_router_data<'a, F: Clone
         test_mode,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -1110,6 +1235,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -1202,6 +1328,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -1224,18 +1351,21 @@ pub fn is_merchant_enabled_for_payment_id_as_connector_request_id(
     config_map.contains(merchant_id)
 }
 
+/// The `default_scope` only decides the reference sent to the connector, the attempt id generation
+/// and manual retries depend on the merchants listed in `merchant_ids_send_payment_id_as_connector_request_id`
 pub fn get_connector_request_reference_id(
     conf: &Settings,
     merchant_id: &common_utils::id_type::MerchantId,
     payment_attempt: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt,
 ) -> String {
-    let is_config_enabled_for_merchant =
-        is_merchant_enabled_for_payment_id_as_connector_request_id(conf, merchant_id);
-    // Send payment_id if config is enabled for a merchant, else send attempt_id
-    if is_config_enabled_for_merchant {
-        payment_attempt.payment_id.get_string_repr().to_owned()
-    } else {
-        payment_attempt.attempt_id.clone()
+    match conf
+        .connector_request_reference_id_config
+        .get_scope(merchant_id)
+    {
+        ConnectorRequestReferenceIdScope::Payment => {
+            payment_attempt.payment_id.get_string_repr().to_owned()
+        }
+        ConnectorRequestReferenceIdScope::Attempt => payment_attempt.attempt_id.clone(),
     }
 }
 
@@ -1377,6 +1507,10 @@ pub fn get_external_authentication_request_poll_id(
     payment_id.get_external_authentication_request_poll_id()
 }
 
+pub fn get_wait_screen_request_poll_id(payment_id: &common_utils::id_type::PaymentId) -> String {
+    payment_id.get_wait_screen_request_poll_id()
+}
+
 pub fn get_html_redirect_response_for_external_authentication(
     return_url_with_query_params: String,
     payment_response: &api_models::payments::PaymentsResponse,
@@ -1477,7 +1611,7 @@ pub fn get_request_incremental_authorization_value(
     Some(request_incremental_authorization
         .map(|request_incremental_authorization| {
             if request_incremental_authorization {
-                if capture_method == Some(common_enums::CaptureMethod::Automatic) {
+                if capture_method.map_or(false, common_enums::CaptureMethod::is_automatic) {
                     Err(errors::ApiErrorResponse::NotSupported { message: "incremental authorization is not supported when capture_method is automatic".to_owned() })?
                 }
                 Ok(RequestIncrementalAuthorization::True)
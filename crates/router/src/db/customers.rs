@@ -1546,3 +1546,101 @@ impl CustomerInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 }
+
+#[cfg(test)]
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use common_utils::{
+        type_name,
+        types::keymanager::{Identifier, KeyManagerState},
+    };
+    use masking::{PeekInterface, Secret};
+    use time::macros::datetime;
+
+    use crate::{
+        services,
+        types::domain::{self, behaviour::Conversion},
+    };
+
+    #[tokio::test]
+    async fn test_convert_back_customer_encrypted_with_previous_key() {
+        let key_manager_state = &KeyManagerState {
+            enabled: Some(false),
+            url: String::new(),
+            client_idle_timeout: None,
+            #[cfg(feature = "km_forward_x_request_id")]
+            request_id: None,
+            #[cfg(feature = "keymanager_mtls")]
+            ca: Secret::new(String::new()),
+            #[cfg(feature = "keymanager_mtls")]
+            cert: Secret::new(String::new()),
+        };
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant1")).unwrap();
+        let identifier = Identifier::Merchant(merchant_id.clone());
+        let current_key = Secret::new(services::generate_aes256_key().unwrap().to_vec());
+        let previous_key = Secret::new(services::generate_aes256_key().unwrap().to_vec());
+
+        // Customer data written before the key rotation is still encrypted with the previous key
+        let name = domain::types::crypto_operation(
+            key_manager_state,
+            type_name!(domain::Customer),
+            domain::types::CryptoOperation::Encrypt(Secret::<String>::new("John Doe".to_string())),
+            identifier.clone(),
+            previous_key.peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .unwrap();
+        let customer = domain::Customer {
+            customer_id: common_utils::id_type::CustomerId::try_from(Cow::from("cus_rotated"))
+                .unwrap(),
+            merchant_id,
+            name: Some(name),
+            email: None,
+            phone: None,
+            phone_country_code: None,
+            description: None,
+            created_at: datetime!(2023-02-01 0:00),
+            metadata: None,
+            modified_at: datetime!(2023-02-01 0:00),
+            connector_customer: None,
+            address_id: None,
+            default_payment_method_id: None,
+            updated_by: None,
+            version: common_enums::ApiVersion::V1,
+        }
+        .convert()
+        .await
+        .unwrap();
+
+        let decrypted_customer = domain::Customer::convert_back_with_fallback_key(
+            key_manager_state,
+            customer.clone(),
+            &current_key,
+            Some(&previous_key),
+            identifier.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            decrypted_customer
+                .name
+                .map(|name| name.into_inner().peek().clone()),
+            Some("John Doe".to_string())
+        );
+
+        assert!(domain::Customer::convert_back_with_fallback_key(
+            key_manager_state,
+            customer,
+            &current_key,
+            None,
+            identifier,
+        )
+        .await
+        .is_err());
+    }
+}
@@ -58,7 +58,7 @@ use super::{
 };
 use crate::{
     configs::Settings,
-    consts,
+    connector, consts,
     core::{
         api_locking,
         errors::{self, CustomResult},
@@ -212,7 +212,10 @@ where
                         },
                         None => serde_json::Value::Null,
                     };
-                    let request_url = request.url.clone();
+                    let request_url = connector::utils::redact_url_query(
+                        &request.url,
+                        consts::SENSITIVE_URL_QUERY_PARAMS,
+                    );
                     let request_method = request.method;
                     let current_time = Instant::now();
                     let response =
@@ -288,6 +291,8 @@ where
                                 }
                                 Err(body) => {
                                     router_data.connector_http_status_code = Some(body.status_code);
+                                    router_data.connector_request_id =
+                                        get_connector_request_id(body.headers.as_ref());
                                     router_data.external_latency = Some(
                                         router_data
                                             .external_latency
@@ -372,7 +377,7 @@ pub async fn call_connector_api(
 ) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
     let current_time = Instant::now();
     let headers = request.headers.clone();
-    let url = request.url.clone();
+    let url = connector::utils::redact_url_query(&request.url, consts::SENSITIVE_URL_QUERY_PARAMS);
     let response = state
         .api_client
         .send_request(state, request, None, true)
@@ -1978,10 +1983,37 @@ pub fn get_payment_link_status(
     }
 }
 
+/// Fetches the connector's own request identifier from the response headers, if present
+pub fn get_connector_request_id(headers: Option<&http::HeaderMap>) -> Option<String> {
+    headers.and_then(|headers| {
+        consts::CONNECTOR_REQUEST_ID_HEADERS
+            .iter()
+            .find_map(|header_name| headers.get(*header_name))
+            .and_then(|header_value| header_value.to_str().ok())
+            .map(ToOwned::to_owned)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
     fn test_mime_essence() {
         assert_eq!(mime::APPLICATION_JSON.essence_str(), "application/json");
     }
+
+    #[test]
+    fn test_get_connector_request_id() {
+        let mut headers = http::HeaderMap::new();
+        assert_eq!(super::get_connector_request_id(Some(&headers)), None);
+        assert_eq!(super::get_connector_request_id(None), None);
+
+        headers.insert(
+            "Request-Id",
+            http::HeaderValue::from_static("req_1234567890"),
+        );
+        assert_eq!(
+            super::get_connector_request_id(Some(&headers)),
+            Some("req_1234567890".to_string())
+        );
+    }
 }
@@ -66,6 +66,7 @@ fn get_default_router_data<F, Req, Resp>(
         test_mode: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         dispute_id: None,
@@ -61,7 +61,7 @@ pub use hyperswitch_domain_models::{
         PaymentsPreProcessingData, PaymentsRejectData, PaymentsSessionData, PaymentsSyncData,
         PaymentsTaxCalculationData, RefundsData, ResponseId, RetrieveFileRequestData,
         SdkPaymentsSessionUpdateData, SetupMandateRequestData, SubmitEvidenceRequestData,
-        SyncRequestType, UploadFileRequestData, VerifyWebhookSourceRequestData,
+        SyncRequestType, UploadFileRequestData, VerifyWebhookSourceRequestData, WalletMerchantData,
     },
     router_response_types::{
         AcceptDisputeResponse, CaptureSyncResponse, DefendDisputeResponse, MandateReference,
@@ -276,7 +276,8 @@ impl Capturable for PaymentsAuthorizeData {
             .capture_method
             .unwrap_or_default()
         {
-            common_enums::CaptureMethod::Automatic => {
+            common_enums::CaptureMethod::Automatic
+            | common_enums::CaptureMethod::SequentialAutomatic => {
                 let intent_status = common_enums::IntentStatus::foreign_from(attempt_status);
                 match intent_status {
                     common_enums::IntentStatus::Succeeded
@@ -353,7 +354,8 @@ impl Capturable for CompleteAuthorizeData {
             .capture_method
             .unwrap_or_default()
         {
-            common_enums::CaptureMethod::Automatic => {
+            common_enums::CaptureMethod::Automatic
+            | common_enums::CaptureMethod::SequentialAutomatic => {
                 let intent_status = common_enums::IntentStatus::foreign_from(attempt_status);
                 match intent_status {
                     common_enums::IntentStatus::Succeeded|
@@ -901,6 +903,7 @@ impl<F1, F2, T1, T2> ForeignFrom<(&RouterData<F1, T1, PaymentsResponseData>, T2)
             connector_api_version: data.connector_api_version.clone(),
             connector_http_status_code: data.connector_http_status_code,
             external_latency: data.external_latency,
+            connector_request_id: data.connector_request_id.clone(),
             apple_pay_flow: data.apple_pay_flow.clone(),
             frm_metadata: data.frm_metadata.clone(),
             dispute_id: data.dispute_id.clone(),
@@ -965,6 +968,7 @@ impl<F1, F2>
             connector_api_version: None,
             connector_http_status_code: data.connector_http_status_code,
             external_latency: data.external_latency,
+            connector_request_id: data.connector_request_id.clone(),
             apple_pay_flow: None,
             frm_metadata: None,
             refund_id: None,
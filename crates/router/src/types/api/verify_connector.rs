@@ -107,6 +107,7 @@ impl VerifyConnectorData {
             payment_method_balance: None,
             connector_http_status_code: None,
             external_latency: None,
+            connector_request_id: None,
             apple_pay_flow: None,
             frm_metadata: None,
             refund_id: None,
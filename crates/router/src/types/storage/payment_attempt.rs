@@ -190,6 +190,8 @@ mod tests {
             organization_id: Default::default(),
             shipping_cost: Default::default(),
             order_tax_amount: Default::default(),
+            connector_request_id: Default::default(),
+            decline_category: Default::default(),
         };
 
         let store = state
@@ -278,6 +280,8 @@ mod tests {
             organization_id: Default::default(),
             shipping_cost: Default::default(),
             order_tax_amount: Default::default(),
+            connector_request_id: Default::default(),
+            decline_category: Default::default(),
         };
         let store = state
             .stores
@@ -379,6 +383,8 @@ mod tests {
             organization_id: Default::default(),
             shipping_cost: Default::default(),
             order_tax_amount: Default::default(),
+            connector_request_id: Default::default(),
+            decline_category: Default::default(),
         };
         let store = state
             .stores
@@ -401,4 +407,131 @@ mod tests {
         // checking it after fetch
         assert_eq!(response.mandate_id, Some("man_121212".to_string()));
     }
+
+    #[tokio::test]
+    /// Example of unit test
+    /// Kind of test: state-based testing
+    async fn test_payment_attempt_error_update_persists_connector_error_details() {
+        let state = create_single_connection_test_transaction_pool().await;
+        let current_time = common_utils::date_time::now();
+        let payment_id =
+            common_utils::id_type::PaymentId::generate_test_payment_id_for_sample_data();
+        let attempt_id = Uuid::new_v4().to_string();
+        let merchant_id = common_utils::id_type::MerchantId::new_from_unix_timestamp();
+        let connector = types::Connector::DummyConnector1.to_string();
+
+        let payment_attempt = PaymentAttemptNew {
+            payment_id: payment_id.clone(),
+            merchant_id: merchant_id.clone(),
+            connector: Some(connector),
+            created_at: current_time.into(),
+            modified_at: current_time.into(),
+            attempt_id: attempt_id.clone(),
+            status: enums::AttemptStatus::Pending,
+            amount: Default::default(),
+            net_amount: Default::default(),
+            currency: Default::default(),
+            save_to_locker: Default::default(),
+            error_message: Default::default(),
+            offer_amount: Default::default(),
+            surcharge_amount: Default::default(),
+            tax_amount: Default::default(),
+            payment_method_id: Default::default(),
+            payment_method: Default::default(),
+            capture_method: Default::default(),
+            capture_on: Default::default(),
+            confirm: Default::default(),
+            authentication_type: Default::default(),
+            last_synced: Default::default(),
+            cancellation_reason: Default::default(),
+            amount_to_capture: Default::default(),
+            mandate_id: Default::default(),
+            browser_info: Default::default(),
+            payment_token: Default::default(),
+            error_code: Default::default(),
+            connector_metadata: Default::default(),
+            payment_experience: Default::default(),
+            payment_method_type: Default::default(),
+            payment_method_data: Default::default(),
+            business_sub_label: Default::default(),
+            straight_through_algorithm: Default::default(),
+            preprocessing_step_id: Default::default(),
+            mandate_details: Default::default(),
+            error_reason: Default::default(),
+            connector_response_reference_id: Default::default(),
+            multiple_capture_count: Default::default(),
+            amount_capturable: Default::default(),
+            updated_by: Default::default(),
+            authentication_data: Default::default(),
+            encoded_data: Default::default(),
+            merchant_connector_id: Default::default(),
+            unified_code: Default::default(),
+            unified_message: Default::default(),
+            external_three_ds_authentication_attempted: Default::default(),
+            authentication_connector: Default::default(),
+            authentication_id: Default::default(),
+            mandate_data: Default::default(),
+            payment_method_billing_address_id: Default::default(),
+            fingerprint_id: Default::default(),
+            charge_id: Default::default(),
+            client_source: Default::default(),
+            client_version: Default::default(),
+            customer_acceptance: Default::default(),
+            profile_id: common_utils::generate_profile_id_of_default_length(),
+            organization_id: Default::default(),
+            shipping_cost: Default::default(),
+            order_tax_amount: Default::default(),
+            connector_request_id: Default::default(),
+            decline_category: Default::default(),
+        };
+        let store = state
+            .stores
+            .get(state.conf.multitenancy.get_tenant_names().first().unwrap())
+            .unwrap();
+        let payment_attempt = store
+            .insert_payment_attempt(payment_attempt, enums::MerchantStorageScheme::PostgresOnly)
+            .await
+            .unwrap();
+
+        store
+            .update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                PaymentAttemptUpdate::ErrorUpdate {
+                    connector: None,
+                    status: enums::AttemptStatus::Failure,
+                    error_code: Some(Some("card_declined".to_string())),
+                    error_message: Some(Some("Your card was declined".to_string())),
+                    error_reason: None,
+                    amount_capturable: Some(MinorUnit::new(0)),
+                    updated_by: enums::MerchantStorageScheme::PostgresOnly.to_string(),
+                    unified_code: None,
+                    unified_message: None,
+                    connector_transaction_id: None,
+                    payment_method_data: None,
+                    authentication_type: None,
+                    connector_request_id: Some("req_1234567890".to_string()),
+                    decline_category: Some("do_default".to_string()),
+                },
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+
+        let response = store
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &payment_id,
+                &merchant_id,
+                &attempt_id,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, enums::AttemptStatus::Failure);
+        assert_eq!(
+            response.connector_request_id,
+            Some("req_1234567890".to_string())
+        );
+        assert_eq!(response.decline_category, Some("do_default".to_string()));
+    }
 }
@@ -1137,6 +1137,8 @@ where
             &state.base_url,
             &operation,
             &state.conf.connector_request_reference_id_config,
+            &state.conf.manual_retry_config,
+            &state.conf.latency_header_config,
             None,
             None,
             None,
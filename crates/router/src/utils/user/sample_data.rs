@@ -333,6 +333,8 @@ pub async fn generate_sample_data(
             organization_id: org_id.clone(),
             shipping_cost: None,
             order_tax_amount: None,
+            connector_request_id: None,
+            decline_category: None,
         };
 
         let refund = if refunds_count < number_of_refunds && !is_failed_payment {
@@ -153,6 +153,8 @@ impl ProcessTrackerWorkflow<SessionState> for PaymentsSyncWorkflow {
                             connector_transaction_id: None,
                             payment_method_data: None,
                             authentication_type: None,
+                            connector_request_id: None,
+                            decline_category: None,
                         };
 
                     payment_data.payment_attempt = db
@@ -122,6 +122,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         connector_http_status_code: None,
         apple_pay_flow: None,
         external_latency: None,
+        connector_request_id: None,
         frm_metadata: None,
         refund_id: None,
         dispute_id: None,
@@ -191,6 +192,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         connector_http_status_code: None,
         apple_pay_flow: None,
         external_latency: None,
+        connector_request_id: None,
         frm_metadata: None,
         refund_id: None,
         dispute_id: None,
@@ -539,6 +539,7 @@ pub trait ConnectorActions: Connector {
             connector_http_status_code: None,
             apple_pay_flow: None,
             external_latency: None,
+            connector_request_id: None,
             frm_metadata: None,
             refund_id: None,
             dispute_id: None,
@@ -179,13 +179,24 @@ async fn payments_create_core() {
         allowed_payment_method_types: None,
         ephemeral_key: None,
         manual_retry_allowed: None,
+        suggest_alternate_payment_method: None,
         connector_transaction_id: None,
+        connector_request_id: None,
+        connector_customer_id: None,
         frm_message: None,
+        risk_score: None,
+        risk_decision: None,
         metadata: None,
         connector_metadata: None,
+        bnpl_order_status: None,
+        capture_executed: None,
+        amount_excluding_surcharge: None,
+        selected_card_network: None,
+        available_card_networks: None,
         feature_metadata: None,
         reference_id: None,
         payment_link: None,
+        payment_link_expired: None,
         profile_id: None,
         surcharge_details: None,
         attempt_count: 1,
@@ -195,6 +206,7 @@ async fn payments_create_core() {
         authorization_count: None,
         incremental_authorizations: None,
         external_authentication_details: None,
+        cavv_present: None,
         external_3ds_authentication_attempted: None,
         expires_on: None,
         fingerprint: None,
@@ -206,7 +218,10 @@ async fn payments_create_core() {
         frm_metadata: None,
         merchant_order_reference_id: None,
         order_tax_amount: None,
+        amount_breakdown: None,
         connector_mandate_id: None,
+        connector_error_details: None,
+        decline_category: None,
     };
 
     let expected_response =
@@ -446,13 +461,24 @@ async fn payments_create_core_adyen_no_redirect() {
             allowed_payment_method_types: None,
             ephemeral_key: None,
             manual_retry_allowed: None,
+            suggest_alternate_payment_method: None,
             connector_transaction_id: None,
+            connector_request_id: None,
+            connector_customer_id: None,
             frm_message: None,
+            risk_score: None,
+            risk_decision: None,
             metadata: None,
             connector_metadata: None,
+            bnpl_order_status: None,
+            capture_executed: None,
+            amount_excluding_surcharge: None,
+            selected_card_network: None,
+            available_card_networks: None,
             feature_metadata: None,
             reference_id: None,
             payment_link: None,
+            payment_link_expired: None,
             profile_id: None,
             surcharge_details: None,
             attempt_count: 1,
@@ -462,6 +488,7 @@ async fn payments_create_core_adyen_no_redirect() {
             authorization_count: None,
             incremental_authorizations: None,
             external_authentication_details: None,
+            cavv_present: None,
             external_3ds_authentication_attempted: None,
             expires_on: None,
             fingerprint: None,
@@ -473,7 +500,10 @@ async fn payments_create_core_adyen_no_redirect() {
             frm_metadata: None,
             merchant_order_reference_id: None,
             order_tax_amount: None,
+            amount_breakdown: None,
             connector_mandate_id: None,
+            connector_error_details: None,
+            decline_category: None,
         },
         vec![],
     ));
@@ -189,6 +189,8 @@ impl PaymentAttemptInterface for MockDb {
             profile_id: payment_attempt.profile_id,
             shipping_cost: payment_attempt.shipping_cost,
             order_tax_amount: payment_attempt.order_tax_amount,
+            connector_request_id: payment_attempt.connector_request_id,
+            decline_category: payment_attempt.decline_category,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -538,6 +538,8 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     profile_id: payment_attempt.profile_id.clone(),
                     shipping_cost: payment_attempt.shipping_cost,
                     order_tax_amount: payment_attempt.order_tax_amount,
+                    connector_request_id: payment_attempt.connector_request_id,
+                    decline_category: payment_attempt.decline_category,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1452,6 +1454,8 @@ impl DataModelExt for PaymentAttempt {
             profile_id: self.profile_id,
             shipping_cost: self.shipping_cost,
             order_tax_amount: self.order_tax_amount,
+            connector_request_id: self.connector_request_id,
+            decline_category: self.decline_category,
         }
     }
 
@@ -1523,6 +1527,8 @@ impl DataModelExt for PaymentAttempt {
             profile_id: storage_model.profile_id,
             shipping_cost: storage_model.shipping_cost,
             order_tax_amount: storage_model.order_tax_amount,
+            connector_request_id: storage_model.connector_request_id,
+            decline_category: storage_model.decline_category,
         }
     }
 }
@@ -1604,6 +1610,8 @@ impl DataModelExt for PaymentAttempt {
             profile_id: self.profile_id,
             shipping_cost: self.shipping_cost,
             order_tax_amount: self.order_tax_amount,
+            connector_request_id: self.connector_request_id,
+            decline_category: self.decline_category,
         }
     }
 
@@ -1675,6 +1683,8 @@ impl DataModelExt for PaymentAttempt {
             profile_id: storage_model.profile_id,
             shipping_cost: storage_model.shipping_cost,
             order_tax_amount: storage_model.order_tax_amount,
+            connector_request_id: storage_model.connector_request_id,
+            decline_category: storage_model.decline_category,
         }
     }
 }
@@ -1756,6 +1766,8 @@ impl DataModelExt for PaymentAttemptNew {
             profile_id: self.profile_id,
             shipping_cost: self.shipping_cost,
             order_tax_amount: self.order_tax_amount,
+            connector_request_id: self.connector_request_id,
+            decline_category: self.decline_category,
         }
     }
 
@@ -1826,6 +1838,8 @@ impl DataModelExt for PaymentAttemptNew {
             profile_id: storage_model.profile_id,
             shipping_cost: storage_model.shipping_cost,
             order_tax_amount: storage_model.order_tax_amount,
+            connector_request_id: storage_model.connector_request_id,
+            decline_category: storage_model.decline_category,
         }
     }
 }
@@ -2072,6 +2086,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_request_id,
+                decline_category,
             } => DieselPaymentAttemptUpdate::ErrorUpdate {
                 connector,
                 status,
@@ -2085,6 +2101,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_request_id,
+                decline_category,
             },
             Self::CaptureUpdate {
                 multiple_capture_count,
@@ -2428,6 +2446,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_request_id,
+                decline_category,
             } => Self::ErrorUpdate {
                 connector,
                 status,
@@ -2441,6 +2461,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_request_id,
+                decline_category,
             },
             DieselPaymentAttemptUpdate::CaptureUpdate {
                 amount_to_capture,
@@ -0,0 +1 @@
+ALTER TYPE "CaptureStatus" ADD VALUE IF NOT EXISTS 'cancelled';
@@ -0,0 +1 @@
+ALTER TYPE "CaptureMethod" ADD VALUE IF NOT EXISTS 'sequential_automatic';